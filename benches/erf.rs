@@ -0,0 +1,48 @@
+#![feature(test)]
+
+extern crate test;
+
+use implied_vol::{erf, erf_slice, erfc, erfc_slice};
+use test::Bencher;
+
+fn inputs() -> Vec<f64> {
+    (-4096..4096).map(|i| i as f64 / 512.0).collect()
+}
+
+#[bench]
+fn erf_element_wise(b: &mut Bencher) {
+    let input = inputs();
+    let mut out = vec![0.0; input.len()];
+    b.iter(|| {
+        for (o, &x) in out.iter_mut().zip(&input) {
+            *o = erf(test::black_box(x));
+        }
+        test::black_box(&mut out);
+    });
+}
+
+#[bench]
+fn erf_slice_wise(b: &mut Bencher) {
+    let input = inputs();
+    let mut out = vec![0.0; input.len()];
+    b.iter(|| erf_slice(test::black_box(&input), test::black_box(&mut out)));
+}
+
+#[bench]
+fn erfc_element_wise(b: &mut Bencher) {
+    let input = inputs();
+    let mut out = vec![0.0; input.len()];
+    b.iter(|| {
+        for (o, &x) in out.iter_mut().zip(&input) {
+            *o = erfc(test::black_box(x));
+        }
+        test::black_box(&mut out);
+    });
+}
+
+#[bench]
+fn erfc_slice_wise(b: &mut Bencher) {
+    let input = inputs();
+    let mut out = vec![0.0; input.len()];
+    b.iter(|| erfc_slice(test::black_box(&input), test::black_box(&mut out)));
+}
@@ -4,7 +4,7 @@ use rand::Rng;
 
 extern crate test;
 
-use implied_vol::implied_black_volatility;
+use implied_vol::{implied_black_volatility, implied_black_volatility_slice_tol, BlackSolver};
 use test::Bencher;
 
 #[bench]
@@ -17,6 +17,16 @@ fn call_atm(b: &mut Bencher) {
     b.iter(|| implied_black_volatility(price, f, k, t, q));
 }
 
+#[bench]
+fn call_deep_otm(b: &mut Bencher) {
+    let f = 1.0;
+    let k = 1.5;
+    let t = 1.0;
+    let q = true;
+    let price = implied_vol::calculate_european_option_price_by_black_scholes(f, k, 0.03, t, q);
+    b.iter(|| implied_black_volatility(price, f, k, t, q));
+}
+
 #[bench]
 fn call_itm(b: &mut Bencher) {
     let seed: [u8; 32] = [13; 32];
@@ -77,3 +87,54 @@ fn put_otm(b: &mut Bencher) {
     let q = false;
     b.iter(|| implied_black_volatility(price, f, k, t, q));
 }
+
+#[bench]
+fn solver_call_itm(b: &mut Bencher) {
+    let seed: [u8; 32] = [13; 32];
+    let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+    let (r, r2, r3): (f64, f64, f64) = rng.gen();
+    let price = 1.0 * (1.0 - r) + 1.0 * r * r2;
+    let f = 1.0;
+    let k = 1.0 * r;
+    let t = 1e5 * r3;
+    let q = true;
+    let solver = BlackSolver::new();
+    b.iter(|| solver.solve(price, f, k, t, q));
+}
+
+fn bench_slice(b: &mut Bencher, rel_tol: f64) {
+    let n = 1_000;
+    let seed: [u8; 32] = [13; 32];
+    let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+    let (mut prices, mut fs, mut ks, mut ts, mut qs) = (
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+    );
+    for i in 0..n {
+        let (r, r2, r3): (f64, f64, f64) = rng.gen();
+        let (f, k, t, q) = (1.0, 0.5 + r, 0.1 + 2.0 * r3, i % 2 == 0);
+        let sigma = 0.1 + 0.5 * r2;
+        let price =
+            implied_vol::calculate_european_option_price_by_black_scholes(f, k, sigma, t, q);
+        prices.push(price);
+        fs.push(f);
+        ks.push(k);
+        ts.push(t);
+        qs.push(q);
+    }
+    let mut out = vec![0.0; n];
+    b.iter(|| implied_black_volatility_slice_tol(&prices, &fs, &ks, &ts, &qs, rel_tol, &mut out));
+}
+
+#[bench]
+fn slice_full_precision(b: &mut Bencher) {
+    bench_slice(b, 0.0);
+}
+
+#[bench]
+fn slice_rel_tol_1e_8(b: &mut Bencher) {
+    bench_slice(b, 1e-8);
+}
@@ -0,0 +1,84 @@
+use crate::bachelier::{bachelier, implied_normal_volatility};
+use crate::lets_be_rational::{black, implied_black_volatility};
+
+/// The largest relative round-trip errors of the pricing and inversion functions over a fixed grid of options.
+///
+/// A volatility error is that of the volatility recovered from the price at a known volatility, and a price error
+/// that of the price recomputed at the volatility implied by a known price. The grid is confined to moderate
+/// moneyness, since far in the wings a price moves by many times the relative change of the volatility, so that
+/// even a correctly rounded volatility reprices with a large relative error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccuracyReport {
+    /// The largest relative error of a black price.
+    pub black_price: f64,
+    /// The largest relative error of an implied black volatility.
+    pub black_vol: f64,
+    /// The largest relative error of a Bachelier price.
+    pub bachelier_price: f64,
+    /// The largest relative error of an implied normal volatility.
+    pub bachelier_vol: f64,
+}
+
+impl AccuracyReport {
+    /// The bound on every error of the report that the crate's tests guarantee.
+    pub const BOUND: f64 = 64.0 * f64::EPSILON;
+
+    /// Returns whether every error of the report is within [`AccuracyReport::BOUND`].
+    pub fn is_within_bound(&self) -> bool {
+        [
+            self.black_price,
+            self.black_vol,
+            self.bachelier_price,
+            self.bachelier_vol,
+        ]
+        .iter()
+        .all(|&error| error <= Self::BOUND)
+    }
+}
+
+fn relative_error(actual: f64, expected: f64) -> f64 {
+    ((actual - expected) / expected).abs()
+}
+
+pub(crate) fn verify_accuracy() -> AccuracyReport {
+    let mut report = AccuracyReport {
+        black_price: 0.0,
+        black_vol: 0.0,
+        bachelier_price: 0.0,
+        bachelier_vol: 0.0,
+    };
+    for x in [-0.5_f64, -0.2, 0.0, 0.2, 0.5] {
+        for s in [0.1, 0.2, 0.5, 1.0, 2.0] {
+            let (k, t) = ((-x).exp(), 1.0);
+            // The out-of-the-money option, whose price carries no intrinsic value that would round the volatility away.
+            let q = x <= 0.0;
+            let price = black(1.0, k, s, t, q);
+            let vol = implied_black_volatility(price, 1.0, k, t, q);
+            report.black_vol = report.black_vol.max(relative_error(vol, s));
+            let reprice = black(1.0, k, vol, t, q);
+            report.black_price = report.black_price.max(relative_error(reprice, price));
+        }
+    }
+    for k in [-0.02, -0.01, -0.002, 0.0, 0.002, 0.01, 0.02] {
+        for sigma in [0.001, 0.005, 0.01, 0.02] {
+            let t = 2.0;
+            let q = k >= 0.0;
+            let price = bachelier(0.0, k, sigma, t, q);
+            let vol = implied_normal_volatility(price, 0.0, k, t, q);
+            report.bachelier_vol = report.bachelier_vol.max(relative_error(vol, sigma));
+            let reprice = bachelier(0.0, k, vol, t, q);
+            report.bachelier_price = report.bachelier_price.max(relative_error(reprice, price));
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_is_within_bound() {
+        assert!(verify_accuracy().is_within_bound());
+    }
+}
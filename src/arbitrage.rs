@@ -0,0 +1,95 @@
+pub(crate) fn is_arbitrage_free(forward: f64, strikes: &[f64], call_prices: &[f64]) -> bool {
+    assert_eq!(strikes.len(), call_prices.len());
+    let within_bounds = strikes
+        .iter()
+        .zip(call_prices)
+        .all(|(&k, &c)| (forward - k).max(0.0) <= c && c <= forward);
+    if !within_bounds {
+        return false;
+    }
+    let mut previous_slope = -1.0;
+    for (k, c) in strikes.windows(2).zip(call_prices.windows(2)) {
+        if k[1] <= k[0] {
+            return false;
+        }
+        let slope = (c[1] - c[0]) / (k[1] - k[0]);
+        // Call prices decrease in strike no faster than the forward payoff and are convex.
+        if slope > 0.0 || slope < previous_slope {
+            return false;
+        }
+        previous_slope = slope;
+    }
+    true
+}
+
+pub(crate) fn forward_volatility(sigma1: f64, t1: f64, sigma2: f64, t2: f64) -> Option<f64> {
+    let forward_variance = (sigma2 * sigma2 * t2 - sigma1 * sigma1 * t1) / (t2 - t1);
+    // A negative forward variance means the total variance decreases with the expiry, i.e. calendar arbitrage.
+    (t1 < t2 && forward_variance >= 0.0).then_some(forward_variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lets_be_rational::black;
+
+    #[test]
+    fn black_prices_are_arbitrage_free() {
+        let forward = 100.0;
+        let strikes: Vec<f64> = (1..40).map(|i| 5.0 * i as f64).collect();
+        let prices: Vec<f64> = strikes
+            .iter()
+            .map(|&k| black(forward, k, 0.3, 1.0, true))
+            .collect();
+        assert!(is_arbitrage_free(forward, &strikes, &prices));
+    }
+
+    #[test]
+    fn violations() {
+        let forward = 100.0;
+        let strikes = [80.0, 90.0, 100.0, 110.0, 120.0];
+        assert!(is_arbitrage_free(
+            forward,
+            &strikes,
+            &[22.0, 14.0, 8.0, 4.0, 2.0]
+        ));
+        // Not convex: the middle price lies above the chord of its neighbours.
+        assert!(!is_arbitrage_free(
+            forward,
+            &strikes,
+            &[22.0, 14.0, 9.5, 4.0, 2.0]
+        ));
+        // Increasing in strike.
+        assert!(!is_arbitrage_free(
+            forward,
+            &strikes,
+            &[22.0, 14.0, 8.0, 8.5, 9.0]
+        ));
+        // Below intrinsic.
+        assert!(!is_arbitrage_free(
+            forward,
+            &strikes,
+            &[19.0, 12.0, 8.0, 4.0, 2.0]
+        ));
+        // Above the forward.
+        assert!(!is_arbitrage_free(forward, &[0.0, 10.0], &[101.0, 95.0]));
+        // Strikes not increasing.
+        assert!(!is_arbitrage_free(forward, &[90.0, 80.0], &[14.0, 22.0]));
+        assert!(is_arbitrage_free(forward, &[], &[]));
+    }
+
+    #[test]
+    fn forward_volatility_between_expiries() {
+        let sigma = forward_volatility(0.2, 1.0, 0.25, 2.0).unwrap();
+        assert!((sigma - 0.085_f64.sqrt()).abs() <= 2.0 * f64::EPSILON);
+        // A flat term structure has the same forward volatility.
+        assert!(
+            (forward_volatility(0.3, 0.5, 0.3, 1.5).unwrap() - 0.3).abs() <= 2.0 * f64::EPSILON
+        );
+        // Total variance 0.09 at one year falls to 0.08 at two years.
+        assert_eq!(forward_volatility(0.3, 1.0, 0.2, 2.0), None);
+        assert_eq!(forward_volatility(0.2, 1.0, 0.25, 1.0), None);
+        assert_eq!(forward_volatility(0.2, 2.0, 0.25, 1.0), None);
+        assert_eq!(forward_volatility(0.2, 1.0, f64::NAN, 2.0), None);
+    }
+}
@@ -69,11 +69,11 @@ fn phi_tilde_times_x(x: f64) -> f64 {
     ONE_OVER_SQRT_TWO_PI * (-0.5 * (x * x)).exp() * w * (1.0 - g * w)
 }
 
-fn phi_tilde(x: f64) -> f64 {
+pub(crate) fn phi_tilde(x: f64) -> f64 {
     phi_tilde_times_x(x) / x
 }
 
-fn inv_phi_tilde(phi_tilde_star: f64) -> f64 {
+pub(crate) fn inv_phi_tilde(phi_tilde_star: f64) -> f64 {
     if phi_tilde_star > 1.0 {
         return -inv_phi_tilde(1.0 - phi_tilde_star);
     }
@@ -134,22 +134,49 @@ pub(crate) fn bachelier(forward: f64, strike: f64, sigma: f64, t: f64, q: bool)
     s * phi_tilde_times_x(x)
 }
 
-pub(crate) fn implied_normal_volatility(
-    price: f64,
+pub(crate) fn bachelier_checked(
     forward: f64,
     strike: f64,
+    sigma: f64,
     t: f64,
     q: bool,
-) -> f64 {
-    if forward == strike {
-        return price * SQRT_TWO_PI / t.sqrt();
+) -> Option<f64> {
+    if forward.is_nan() || strike.is_nan() || sigma.is_nan() || t.is_nan() || sigma < 0.0 || t < 0.0
+    {
+        return None;
     }
-    let intrinsic = intrinsic_value(forward, strike, q);
-    match price.total_cmp(&intrinsic) {
-        Ordering::Less => f64::NEG_INFINITY,
-        Ordering::Equal => 0.0,
-        Ordering::Greater => {
-            let absolute_moneyness = (forward - strike).abs();
+    Some(bachelier(forward, strike, sigma, t, q))
+}
+
+pub(crate) fn price_bounds(forward: f64, strike: f64, q: bool) -> (f64, f64) {
+    (intrinsic_value(forward, strike, q), f64::INFINITY)
+}
+
+pub(crate) fn atm_price(sigma: f64, t: f64) -> f64 {
+    sigma.abs() * t.sqrt() * ONE_OVER_SQRT_TWO_PI
+}
+
+pub(crate) fn normalise_inputs(price: f64, forward: f64, strike: f64, q: bool) -> (f64, f64, f64) {
+    (
+        price,
+        intrinsic_value(forward, strike, q),
+        (forward - strike).abs(),
+    )
+}
+
+pub(crate) fn implied_normal_volatility_normalised(
+    price: f64,
+    intrinsic: f64,
+    absolute_moneyness: f64,
+    t: f64,
+) -> f64 {
+    // `partial_cmp` rather than `total_cmp`, so that a price of `-0.0` equals a zero intrinsic value. A NaN price
+    // falls through to the formula and stays NaN.
+    match price.partial_cmp(&intrinsic) {
+        Some(Ordering::Less) => f64::NEG_INFINITY,
+        Some(Ordering::Equal) => 0.0,
+        _ if absolute_moneyness == 0.0 => price * SQRT_TWO_PI / t.sqrt(),
+        _ => {
             let phi_tilde_star = (intrinsic - price) / absolute_moneyness;
             let x_star = inv_phi_tilde(phi_tilde_star);
             absolute_moneyness / (x_star * t.sqrt()).abs()
@@ -157,6 +184,94 @@ pub(crate) fn implied_normal_volatility(
     }
 }
 
+pub(crate) fn implied_normal_volatility(
+    price: f64,
+    forward: f64,
+    strike: f64,
+    t: f64,
+    q: bool,
+) -> f64 {
+    // Calls and puts differ only in their intrinsic values, so both are solved on the same representation without
+    // converting one into the other through put-call parity, which would round away the time value of deep
+    // in-the-money options.
+    let (price, intrinsic, absolute_moneyness) = normalise_inputs(price, forward, strike, q);
+    implied_normal_volatility_normalised(price, intrinsic, absolute_moneyness, t)
+}
+
+/// The reasons why [`crate::implied_normal_volatility_checked`] cannot compute an implied normal volatility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalVolError {
+    /// The option price is below the intrinsic value.
+    PriceBelowIntrinsic,
+    /// The price, forward, strike or expiry is NaN or infinite.
+    NonFiniteInput,
+    /// The expiry is negative.
+    NegativeExpiry,
+    /// The expiry is zero and the price is above the intrinsic value, which no volatility attains.
+    TimeValueAtZeroExpiry,
+    /// The implied volatility is too large to be represented as a finite `f64`.
+    Overflow,
+}
+
+impl std::fmt::Display for NormalVolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            NormalVolError::PriceBelowIntrinsic => "option price is below the intrinsic value",
+            NormalVolError::NonFiniteInput => "input is not finite",
+            NormalVolError::NegativeExpiry => "expiry is negative",
+            NormalVolError::TimeValueAtZeroExpiry => {
+                "option price is above the intrinsic value at zero expiry"
+            }
+            NormalVolError::Overflow => "implied volatility overflows",
+        })
+    }
+}
+
+impl std::error::Error for NormalVolError {}
+
+pub(crate) fn implied_normal_volatility_checked(
+    price: f64,
+    forward: f64,
+    strike: f64,
+    t: f64,
+    q: bool,
+) -> Result<f64, NormalVolError> {
+    if !(price.is_finite() && forward.is_finite() && strike.is_finite() && t.is_finite()) {
+        return Err(NormalVolError::NonFiniteInput);
+    }
+    if t < 0.0 {
+        return Err(NormalVolError::NegativeExpiry);
+    }
+    let intrinsic = intrinsic_value(forward, strike, q);
+    if price < intrinsic {
+        return Err(NormalVolError::PriceBelowIntrinsic);
+    }
+    if t == 0.0 && price > intrinsic {
+        return Err(NormalVolError::TimeValueAtZeroExpiry);
+    }
+    let sigma = implied_normal_volatility(price, forward, strike, t, q);
+    // A tiny expiry can scale a finite time value to an infinite volatility.
+    if sigma.is_infinite() {
+        return Err(NormalVolError::Overflow);
+    }
+    Ok(sigma)
+}
+
+pub(crate) fn implied_normal_volatility_bps(
+    price_bps: f64,
+    notional: f64,
+    forward: f64,
+    strike: f64,
+    t: f64,
+    q: bool,
+) -> Option<f64> {
+    if !(notional.is_finite() && notional > 0.0) {
+        return None;
+    }
+    // The premium is price_bps * 1e-4 * notional, and the Bachelier price is that of a unit notional.
+    implied_normal_volatility_checked(price_bps * 1e-4, forward, strike, t, q).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,4 +394,311 @@ mod tests {
             assert!((price - reprice).abs() <= 2.0 * f64::EPSILON);
         }
     }
+
+    #[test]
+    fn atm_price_and_bounds() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..1000 {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (f, sigma, t) = (-1.0 + 2.0 * r, 0.001 + r2, 10.0 * r3);
+            let atm = atm_price(sigma, t);
+            for q in [true, false] {
+                let price = bachelier(f, f, sigma, t, q);
+                assert!((price - atm).abs() <= 2.0 * f64::EPSILON * atm);
+                assert_eq!(price_bounds(f, f, q), (0.0, f64::INFINITY));
+            }
+        }
+        assert_eq!(price_bounds(0.03, 0.01, true), (0.03 - 0.01, f64::INFINITY));
+        assert_eq!(price_bounds(0.03, 0.01, false), (0.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn put_call_parity() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (forward, strike) = (1.0, 0.5 + r);
+            let (sigma, t) = (0.01 + r2, 0.1 + 10.0 * r3);
+            let out_of_the_money = bachelier(forward, strike, sigma, t, strike > forward);
+            if out_of_the_money < 1e-3 * (forward - strike).abs() {
+                // The time value of the in-the-money leg is rounded away by parity itself.
+                continue;
+            }
+            let (call, put) = if strike > forward {
+                (out_of_the_money, out_of_the_money + (strike - forward))
+            } else {
+                (out_of_the_money + (forward - strike), out_of_the_money)
+            };
+            let call_vol = implied_normal_volatility(call, forward, strike, t, true);
+            let put_vol = implied_normal_volatility(put, forward, strike, t, false);
+            assert!((call_vol - put_vol).abs() <= 1e-13);
+        }
+    }
+
+    #[test]
+    fn negative_forward_and_strike() {
+        use crate::normal_distribution::norm_cdf;
+        for (forward, strike) in [
+            (-0.01, -0.02),
+            (-0.02, -0.01),
+            (0.01, -0.005),
+            (-0.01, -0.01),
+        ] {
+            for (sigma, t) in [(0.01, 0.5), (0.005, 2.0), (0.02, 10.0)] {
+                for q in [true, false] {
+                    let s = sigma * f64::sqrt(t);
+                    let d = (forward - strike) / s;
+                    let theta = if q { 1.0 } else { -1.0 };
+                    let expected =
+                        theta * (forward - strike) * norm_cdf(theta * d) + s * norm_pdf(d);
+                    let price = bachelier(forward, strike, sigma, t, q);
+                    assert!((price - expected).abs() <= 1e-14 * expected);
+                    // The model depends on the forward and the strike through their difference only.
+                    let shifted = bachelier(forward + 0.5, strike + 0.5, sigma, t, q);
+                    assert!((price - shifted).abs() <= 1e-14);
+                    let vol = implied_normal_volatility(price, forward, strike, t, q);
+                    assert!((vol - sigma).abs() <= 1e-13 * sigma);
+                    assert_eq!(
+                        implied_normal_volatility_checked(price, forward, strike, t, q),
+                        Ok(vol)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalised_composition() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let f = 1.0;
+            let k = 2.0 * r;
+            let q = i % 2 == 0;
+            let price = intrinsic_value(f, k, q) + r2;
+            let t = 1e5 * r3;
+            let (price_n, intrinsic, absolute_moneyness) = normalise_inputs(price, f, k, q);
+            assert_eq!(
+                implied_normal_volatility_normalised(price_n, intrinsic, absolute_moneyness, t),
+                implied_normal_volatility(price, f, k, t, q)
+            );
+        }
+        let (price, intrinsic, absolute_moneyness) = normalise_inputs(0.5, 100.0, 100.0, true);
+        assert_eq!(
+            implied_normal_volatility_normalised(price, intrinsic, absolute_moneyness, 1.0),
+            implied_normal_volatility(0.5, 100.0, 100.0, 1.0, true)
+        );
+    }
+
+    #[test]
+    fn time_inf() {
+        let t = f64::INFINITY;
+        for q in [true, false] {
+            for (f, k) in [(100.0, 100.0), (100.0, 90.0), (90.0, 100.0)] {
+                let intrinsic = intrinsic_value(f, k, q);
+                for price in [
+                    intrinsic,
+                    intrinsic + 0.01,
+                    intrinsic + 5.0,
+                    intrinsic + 1e5,
+                ] {
+                    assert_eq!(implied_normal_volatility(price, f, k, t, q), 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_rejects_invalid_inputs() {
+        assert_eq!(bachelier_checked(f64::NAN, 90.0, 6.0, 30.0, true), None);
+        assert_eq!(bachelier_checked(100.0, f64::NAN, 6.0, 30.0, false), None);
+        assert_eq!(bachelier_checked(100.0, 90.0, f64::NAN, 30.0, true), None);
+        assert_eq!(bachelier_checked(100.0, 90.0, 6.0, f64::NAN, true), None);
+        assert_eq!(bachelier_checked(100.0, 90.0, -6.0, 30.0, true), None);
+        assert_eq!(bachelier_checked(100.0, 90.0, 6.0, -30.0, true), None);
+        for q in [true, false] {
+            assert_eq!(
+                bachelier_checked(100.0, 90.0, 6.0, 30.0, q),
+                Some(bachelier(100.0, 90.0, 6.0, 30.0, q))
+            );
+        }
+        assert_eq!(bachelier_checked(100.0, 90.0, 0.0, 0.0, true), Some(10.0));
+    }
+
+    #[test]
+    fn phi_tilde_round_trip() {
+        for i in 1..=2000 {
+            let x = -0.005 * i as f64;
+            assert!((inv_phi_tilde(phi_tilde(x)) - x).abs() <= 1e-12 * x.abs().max(1.0));
+            // Above 1, phi_tilde(-x) = 1 - phi_tilde(x) is only known to about EPSILON absolutely, and its derivative
+            // is -norm_pdf(x) / x^2.
+            let y = phi_tilde(-x);
+            let conditioning = f64::EPSILON * x * x / norm_pdf(x);
+            if y > 1.0 {
+                assert!((inv_phi_tilde(y) + x).abs() <= 1e-12 + 4.0 * conditioning);
+            }
+        }
+        assert!(inv_phi_tilde(0.0).is_nan());
+        assert!(inv_phi_tilde(0.5).is_nan());
+        assert!(inv_phi_tilde(1.0).is_nan());
+    }
+
+    #[test]
+    fn inv_phi_tilde_yields_normal_volatility() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3, q): (f64, f64, f64, bool) = rng.gen();
+            let (forward, strike, t) = (1.0, 0.5 + r, 0.1 + 10.0 * r3);
+            let price = bachelier(forward, strike, 0.01 + r2, t, q);
+            let intrinsic = intrinsic_value(forward, strike, q);
+            if price <= intrinsic {
+                continue;
+            }
+            let absolute_moneyness = (forward - strike).abs();
+            let phi_tilde_star = (intrinsic - price) / absolute_moneyness;
+            let x = inv_phi_tilde(phi_tilde_star);
+            assert!(x < 0.0);
+            assert!((phi_tilde(x) - phi_tilde_star).abs() <= 1e-14 * phi_tilde_star.abs());
+            assert_eq!(
+                absolute_moneyness / (x * t.sqrt()).abs(),
+                implied_normal_volatility(price, forward, strike, t, q)
+            );
+        }
+    }
+
+    #[test]
+    fn near_atm_reconstruction() {
+        // Nearly equal forward and strike take the general branch with a tiny absolute moneyness; the volatility must
+        // be recovered as accurately as at the money.
+        let f = 100.0;
+        let t = 1.0;
+        for relative_moneyness in [0.0, 1e-16, 1e-14, 1e-12, 1e-10, 1e-8] {
+            let k = f * (1.0 + relative_moneyness);
+            for i in 1..250 {
+                let sigma = 1e-6 * 1.1_f64.powi(i);
+                for q in [true, false] {
+                    let price = bachelier(f, k, sigma, t, q);
+                    let vol = implied_normal_volatility(price, f, k, t, q);
+                    assert!((vol - sigma).abs() <= 4.0 * f64::EPSILON * sigma);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_errors() {
+        assert_eq!(
+            implied_normal_volatility_checked(5.0, 100.0, 90.0, 1.0, true),
+            Err(NormalVolError::PriceBelowIntrinsic)
+        );
+        for (price, f, k, t) in [
+            (f64::NAN, 100.0, 90.0, 1.0),
+            (20.0, f64::INFINITY, 90.0, 1.0),
+            (20.0, 100.0, f64::NEG_INFINITY, 1.0),
+            (20.0, 100.0, 90.0, f64::INFINITY),
+        ] {
+            assert_eq!(
+                implied_normal_volatility_checked(price, f, k, t, true),
+                Err(NormalVolError::NonFiniteInput)
+            );
+        }
+        assert_eq!(
+            implied_normal_volatility_checked(20.0, 100.0, 90.0, -1.0, true),
+            Err(NormalVolError::NegativeExpiry)
+        );
+        assert_eq!(
+            implied_normal_volatility_checked(20.0, 100.0, 90.0, 30.0, true),
+            Ok(implied_normal_volatility(20.0, 100.0, 90.0, 30.0, true))
+        );
+    }
+
+    #[test]
+    fn signed_zeros_at_the_money() {
+        for k in [1.0, 100.0, -3.0, 0.0] {
+            for f in [k, k + 0.0 * f64::EPSILON, k - 0.0] {
+                for q in [true, false] {
+                    for price in [0.0, -0.0] {
+                        assert_eq!(implied_normal_volatility(price, f, k, 1.0, q), 0.0);
+                        assert_eq!(
+                            implied_normal_volatility_checked(price, f, k, 1.0, q),
+                            Ok(0.0)
+                        );
+                    }
+                    let price = bachelier(f, k, 0.3, 2.0, q);
+                    assert_eq!(price, bachelier(f, k, 0.3, 2.0, !q));
+                    let sigma = implied_normal_volatility(price, f, k, 2.0, q);
+                    assert!((sigma - 0.3).abs() <= 2.0 * f64::EPSILON * 0.3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bps_of_notional() {
+        let notional = 10_000_000.0;
+        let (f, k, t) = (0.03, 0.035, 2.0);
+        for q in [true, false] {
+            let premium = notional * bachelier(f, k, 0.008, t, q);
+            let price_bps = premium / notional * 1e4;
+            let sigma = implied_normal_volatility_bps(price_bps, notional, f, k, t, q).unwrap();
+            let absolute = implied_normal_volatility(premium / notional, f, k, t, q);
+            assert!((sigma - absolute).abs() <= 4.0 * f64::EPSILON * absolute);
+            assert!((sigma - 0.008).abs() <= 1e-14);
+        }
+        assert_eq!(
+            implied_normal_volatility_bps(10.0, 0.0, 0.03, 0.035, 2.0, true),
+            None
+        );
+        assert_eq!(
+            implied_normal_volatility_bps(-10.0, 1e6, 0.03, 0.035, 2.0, true),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_never_returns_infinities() {
+        for (f, k) in [(100.0, 90.0), (100.0, 100.0), (90.0, 100.0)] {
+            for q in [true, false] {
+                let intrinsic = intrinsic_value(f, k, q);
+                let below = intrinsic - 1.0;
+                assert_eq!(
+                    implied_normal_volatility(below, f, k, 1.0, q),
+                    f64::NEG_INFINITY
+                );
+                assert_eq!(
+                    implied_normal_volatility_checked(below, f, k, 1.0, q),
+                    Err(NormalVolError::PriceBelowIntrinsic)
+                );
+                assert_eq!(
+                    implied_normal_volatility(intrinsic + 1.0, f, k, 0.0, q),
+                    f64::INFINITY
+                );
+                assert_eq!(
+                    implied_normal_volatility_checked(intrinsic + 1.0, f, k, 0.0, q),
+                    Err(NormalVolError::TimeValueAtZeroExpiry)
+                );
+                assert_eq!(
+                    implied_normal_volatility_checked(intrinsic, f, k, 0.0, q),
+                    Ok(0.0)
+                );
+            }
+        }
+        for (price, f) in [(1e300, 0.0), (1e300, 1.0)] {
+            assert_eq!(
+                implied_normal_volatility(price, f, 0.0, 1e-300, true),
+                f64::INFINITY
+            );
+            assert_eq!(
+                implied_normal_volatility_checked(price, f, 0.0, 1e-300, true),
+                Err(NormalVolError::Overflow)
+            );
+        }
+    }
 }
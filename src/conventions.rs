@@ -0,0 +1,136 @@
+pub(crate) fn forward_from_spot(spot: f64, rate: f64, dividend_yield: f64, t: f64) -> f64 {
+    spot * ((rate - dividend_yield) * t).exp()
+}
+
+pub(crate) fn spot_from_forward(forward: f64, rate: f64, dividend_yield: f64, t: f64) -> f64 {
+    forward * ((dividend_yield - rate) * t).exp()
+}
+
+/// A day count convention converting a period between two dates into a year fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// Actual days divided by 365.
+    Act365Fixed,
+    /// Actual days divided by 360.
+    Act360,
+    /// The 30/360 bond basis: months count as 30 days, with the 31st of a month treated as the 30th, except for an
+    /// end date on the 31st when the start date is before the 30th.
+    Thirty360,
+}
+
+/// Counts the days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_from_civil((year, month, day): (i32, u32, u32)) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+pub(crate) fn year_fraction(
+    start: (i32, u32, u32),
+    end: (i32, u32, u32),
+    convention: DayCount,
+) -> f64 {
+    match convention {
+        DayCount::Act365Fixed => (days_from_civil(end) - days_from_civil(start)) as f64 / 365.0,
+        DayCount::Act360 => (days_from_civil(end) - days_from_civil(start)) as f64 / 360.0,
+        DayCount::Thirty360 => {
+            let (y1, m1, d1) = start;
+            let (y2, m2, d2) = end;
+            let d1 = d1.min(30);
+            let d2 = if d1 == 30 { d2.min(30) } else { d2 };
+            let days = 360 * (i64::from(y2) - i64::from(y1))
+                + 30 * (i64::from(m2) - i64::from(m1))
+                + (i64::from(d2) - i64::from(d1));
+            days as f64 / 360.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_carry() {
+        for (rate, dividend_yield, t) in [(0.05, 0.05, 2.0), (0.05, 0.02, 0.0), (-0.01, 0.03, 0.0)]
+        {
+            assert_eq!(forward_from_spot(100.0, rate, dividend_yield, t), 100.0);
+            assert_eq!(spot_from_forward(100.0, rate, dividend_yield, t), 100.0);
+        }
+    }
+
+    #[test]
+    fn carry_sign() {
+        assert!(forward_from_spot(100.0, 0.05, 0.02, 1.0) > 100.0);
+        assert!(forward_from_spot(100.0, 0.02, 0.05, 1.0) < 100.0);
+        assert_eq!(
+            forward_from_spot(100.0, 0.05, 0.0, 2.0),
+            100.0 * 0.1_f64.exp()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (rate, dividend_yield, t) in [(0.05, 0.02, 1.0), (0.01, 0.04, 10.0), (-0.005, 0.0, 0.5)]
+        {
+            let forward = forward_from_spot(100.0, rate, dividend_yield, t);
+            let spot = spot_from_forward(forward, rate, dividend_yield, t);
+            assert!((spot - 100.0).abs() <= 2.0 * f64::EPSILON * 100.0);
+        }
+    }
+
+    #[test]
+    fn days_from_civil_known_dates() {
+        assert_eq!(days_from_civil((1970, 1, 1)), 0);
+        assert_eq!(days_from_civil((2000, 3, 1)), 11_017);
+        assert_eq!(days_from_civil((1969, 12, 31)), -1);
+        assert_eq!(
+            days_from_civil((2024, 3, 1)) - days_from_civil((2024, 2, 28)),
+            2
+        );
+        assert_eq!(
+            days_from_civil((2100, 3, 1)) - days_from_civil((2100, 2, 28)),
+            1
+        );
+    }
+
+    #[test]
+    fn year_fraction_conventions() {
+        let (start, end) = ((2024, 1, 1), (2025, 1, 1));
+        assert_eq!(
+            year_fraction(start, end, DayCount::Act365Fixed),
+            366.0 / 365.0
+        );
+        assert_eq!(year_fraction(start, end, DayCount::Act360), 366.0 / 360.0);
+        assert_eq!(year_fraction(start, end, DayCount::Thirty360), 1.0);
+        let (start, end) = ((2023, 2, 28), (2024, 2, 29));
+        assert_eq!(
+            year_fraction(start, end, DayCount::Act365Fixed),
+            366.0 / 365.0
+        );
+        assert_eq!(
+            year_fraction(start, end, DayCount::Thirty360),
+            361.0 / 360.0
+        );
+        assert_eq!(
+            year_fraction((2007, 1, 31), (2007, 2, 28), DayCount::Thirty360),
+            28.0 / 360.0
+        );
+        assert_eq!(
+            year_fraction((2007, 1, 31), (2007, 3, 31), DayCount::Thirty360),
+            60.0 / 360.0
+        );
+        assert_eq!(
+            year_fraction((2007, 1, 15), (2007, 3, 31), DayCount::Thirty360),
+            76.0 / 360.0
+        );
+        assert_eq!(
+            year_fraction((2007, 1, 15), (2007, 7, 15), DayCount::Act360),
+            181.0 / 360.0
+        );
+    }
+}
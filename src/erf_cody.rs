@@ -124,6 +124,52 @@ pub(crate) fn erfc_cody(x: f64) -> f64 {
     result
 }
 
+#[cfg(feature = "error-function")]
+pub(crate) fn erf_cody(x: f64) -> f64 {
+    /* -------------------------------------------------------------------- */
+    /* This subprogram computes approximate values for erf(x). */
+    /*   (see comments heading CALERF). */
+    /*   Author/date: W. J. Cody, January 8, 1985 */
+    /* -------------------------------------------------------------------- */
+    let y = x.abs();
+    if y <= THRESH {
+        let mut ysq = 0.0;
+        if y > XSMALL {
+            ysq = y * y;
+        }
+        let mut xnum = A[4] * ysq;
+        let mut xden = ysq;
+
+        for i in 0..3 {
+            xnum = (xnum + A[i]) * ysq;
+            xden = (xden + B[i]) * ysq;
+        }
+        return x * (xnum + A[3]) / (xden + B[3]);
+    }
+    let result = (0.5 - erfc_cody(y)) + 0.5;
+    if x.is_sign_negative() {
+        -result
+    } else {
+        result
+    }
+}
+
+#[cfg(feature = "error-function")]
+pub(crate) fn erf_slice(input: &[f64], out: &mut [f64]) {
+    assert_eq!(input.len(), out.len());
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = erf_cody(x);
+    }
+}
+
+#[cfg(feature = "error-function")]
+pub(crate) fn erfc_slice(input: &[f64], out: &mut [f64]) {
+    assert_eq!(input.len(), out.len());
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = erfc_cody(x);
+    }
+}
+
 pub(crate) fn erfcx_cody(x: f64) -> f64 {
     /* ------------------------------------------------------------------ */
     /* This subprogram computes approximate values for exp(x*x) * erfc(x). */
@@ -214,8 +260,79 @@ pub(crate) fn erfcx_cody(x: f64) -> f64 {
     result
 }
 
+/// How [`crate::erfcx_with_policy`] treats arguments below `-26.628`, where `erfcx` overflows `f64`.
+#[cfg(feature = "error-function")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErfcxPolicy {
+    /// Return `f64::MAX`, as [`crate::erfcx`] does.
+    Saturate,
+    /// Return `f64::INFINITY`.
+    Infinity,
+    /// Return an [`ErfcxOverflow`] error.
+    Error,
+}
+
+/// The error returned by [`crate::erfcx_with_policy`] under [`ErfcxPolicy::Error`] when `erfcx` overflows `f64`.
+#[cfg(feature = "error-function")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErfcxOverflow {
+    /// The argument for which `erfcx` overflows.
+    pub x: f64,
+}
+
+#[cfg(feature = "error-function")]
+impl std::fmt::Display for ErfcxOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "erfcx({}) overflows f64", self.x)
+    }
+}
+
+#[cfg(feature = "error-function")]
+impl std::error::Error for ErfcxOverflow {}
+
+#[cfg(feature = "error-function")]
+pub(crate) fn erfcx_with_policy(x: f64, policy: ErfcxPolicy) -> Result<f64, ErfcxOverflow> {
+    if x >= XNEG || x.is_nan() {
+        return Ok(erfcx_cody(x));
+    }
+    match policy {
+        ErfcxPolicy::Saturate => Ok(XINF),
+        ErfcxPolicy::Infinity => Ok(f64::INFINITY),
+        ErfcxPolicy::Error => Err(ErfcxOverflow { x }),
+    }
+}
+
+#[cfg(feature = "error-function")]
+pub(crate) fn one_minus_erfcx(x: f64) -> f64 {
+    // NaN takes this branch too, as it would never terminate the series.
+    if x.is_nan() || x.abs() >= 0.25 {
+        return 1.0 - erfcx_cody(x);
+    }
+    // 1 - erfcx(x) = -Σ_{n≥1} (-x)^n / Γ(1 + n/2), summed separately over odd and even n.
+    let x2 = x * x;
+    let mut odd = 2.0 * SQRPI * x;
+    let mut even = -x2;
+    let mut sum = odd + even;
+    let mut n = 1.0;
+    loop {
+        odd *= x2 / (1.0 + 0.5 * n);
+        even *= x2 / (1.5 + 0.5 * n);
+        let next = sum + (odd + even);
+        if next == sum {
+            return sum;
+        }
+        sum = next;
+        n += 2.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "error-function")]
+    use crate::erf_cody::{
+        erf_cody, erf_slice, erfc_slice, erfcx_with_policy, one_minus_erfcx, ErfcxOverflow,
+        ErfcxPolicy,
+    };
     use crate::erf_cody::{erfc_cody, erfcx_cody, THRESH, XBIG, XHUGE, XMAX, XNEG};
 
     #[test]
@@ -333,4 +450,89 @@ mod tests {
         let x = erfcx_cody(XNEG - f64::EPSILON);
         assert_eq!(x, 1.728618506590026e308);
     }
+
+    #[cfg(feature = "error-function")]
+    #[test]
+    fn erf_values() {
+        for (x, expected) in [
+            (0.1, 0.1124629160182849),
+            (0.5, 0.5204998778130465),
+            (1.0, 0.8427007929497149),
+            (2.0, 0.9953222650189527),
+            (5.0, 0.9999999999984626),
+            (-0.3, -0.3286267594591274),
+        ] {
+            let y = erf_cody(x);
+            assert!(((y - expected) / expected).abs() <= 2.0 * f64::EPSILON);
+        }
+        assert_eq!(erf_cody(0.0), 0.0);
+        assert_eq!(erf_cody(XBIG), 1.0);
+        assert_eq!(erf_cody(-XBIG), -1.0);
+    }
+
+    #[cfg(feature = "error-function")]
+    #[test]
+    fn slices() {
+        let input: Vec<f64> = (-1000..=1000).map(|i| i as f64 * 0.01).collect();
+        let mut out = vec![0.0; input.len()];
+        erf_slice(&input, &mut out);
+        for (&x, &y) in input.iter().zip(&out) {
+            assert_eq!(y, erf_cody(x));
+        }
+        erfc_slice(&input, &mut out);
+        for (&x, &y) in input.iter().zip(&out) {
+            assert_eq!(y, erfc_cody(x));
+        }
+    }
+
+    #[cfg(feature = "error-function")]
+    #[test]
+    fn one_minus_erfcx_small_arguments() {
+        for (x, expected) in [
+            (1e-8, 1.128_379_157_095_512_6e-8),
+            (-1e-5, -1.128_389_167_170_738_4e-5),
+            (0.001, 0.001_127_379_918_848_591_4),
+            (0.1, 0.103_543_020_030_873_36),
+            (0.2, 0.190_980_480_098_419_25),
+            (-0.2, -0.272_602_028_483_195_7),
+            (0.24, 0.222_162_538_344_110_37),
+            (0.3, 0.265_400_665_432_344_86),
+            (-1.0, -4.008_980_080_762_283),
+            (2.0, 0.744_604_323_689_494_3),
+        ] {
+            assert!(((one_minus_erfcx(x) - expected) / expected).abs() <= 4.0 * f64::EPSILON);
+        }
+        let x = 1e-8;
+        let expected = 1.128_379_157_095_512_6e-8;
+        let naive = 1.0 - erfcx_cody(x);
+        assert!(((naive - expected) / expected).abs() > 1e-10);
+        assert!(one_minus_erfcx(f64::NAN).is_nan());
+    }
+
+    #[cfg(feature = "error-function")]
+    #[test]
+    fn erfcx_policies() {
+        let x = -30.0;
+        assert_eq!(erfcx_with_policy(x, ErfcxPolicy::Saturate), Ok(f64::MAX));
+        assert_eq!(
+            erfcx_with_policy(x, ErfcxPolicy::Saturate),
+            Ok(erfcx_cody(x))
+        );
+        assert_eq!(
+            erfcx_with_policy(x, ErfcxPolicy::Infinity),
+            Ok(f64::INFINITY)
+        );
+        assert_eq!(
+            erfcx_with_policy(x, ErfcxPolicy::Error),
+            Err(ErfcxOverflow { x })
+        );
+        for policy in [
+            ErfcxPolicy::Saturate,
+            ErfcxPolicy::Infinity,
+            ErfcxPolicy::Error,
+        ] {
+            assert_eq!(erfcx_with_policy(XNEG, policy), Ok(erfcx_cody(XNEG)));
+            assert_eq!(erfcx_with_policy(0.5, policy), Ok(erfcx_cody(0.5)));
+        }
+    }
 }
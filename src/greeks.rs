@@ -0,0 +1,618 @@
+use crate::lets_be_rational::{implied_black_volatility, normalised_vega};
+use crate::normal_distribution::{inverse_norm_cdf, norm_cdf, norm_pdf};
+
+pub(crate) fn d1_d2(f: f64, k: f64, sigma: f64, t: f64) -> (f64, f64) {
+    let s = sigma * t.sqrt();
+    let x = (f / k).ln();
+    if s == 0.0 && x == 0.0 {
+        return (0.0, 0.0);
+    }
+    let d1 = x / s + 0.5 * s;
+    (d1, d1 - s)
+}
+
+pub(crate) fn normalised_delta(x: f64, s: f64, q: bool) -> f64 {
+    let d1 = x / s + 0.5 * s;
+    if q {
+        norm_cdf(d1)
+    } else {
+        -norm_cdf(-d1)
+    }
+}
+
+pub(crate) fn normalised_gamma(x: f64, s: f64) -> f64 {
+    norm_pdf(x / s + 0.5 * s) / s
+}
+
+/// The first- and second-order sensitivities of an undiscounted Black option price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackScholesGreeks {
+    /// The derivative with respect to the forward.
+    pub delta: f64,
+    /// The second derivative with respect to the forward.
+    pub gamma: f64,
+    /// The derivative with respect to the volatility.
+    pub vega: f64,
+    /// The negated derivative with respect to the expiry, i.e. the change in value as time passes.
+    pub theta: f64,
+    /// The derivative of delta with respect to the volatility.
+    pub vanna: f64,
+    /// The second derivative with respect to the volatility.
+    pub volga: f64,
+}
+
+impl BlackScholesGreeks {
+    /// Returns the vega per volatility point, i.e. the change in value for a rise of the volatility by `0.01`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let greeks = implied_vol::black_greeks(100.0, 100.0, 0.2, 1.0, true);
+    /// assert_eq!(greeks.vega_per_percent(), greeks.vega / 100.0);
+    /// ```
+    pub fn vega_per_percent(&self) -> f64 {
+        self.vega / 100.0
+    }
+
+    /// Returns the theta per calendar day, i.e. the change in value over one day of a 365-day year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let greeks = implied_vol::black_greeks(100.0, 100.0, 0.2, 1.0, true);
+    /// assert_eq!(greeks.theta_per_day(), greeks.theta / 365.0);
+    /// ```
+    pub fn theta_per_day(&self) -> f64 {
+        self.theta / 365.0
+    }
+}
+
+pub(crate) fn greeks(f: f64, k: f64, sigma: f64, t: f64, q: bool) -> BlackScholesGreeks {
+    let sqrt_t = t.sqrt();
+    let s = sigma * sqrt_t;
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    let pdf = norm_pdf(d1);
+    let vega = f * pdf * sqrt_t;
+    BlackScholesGreeks {
+        delta: if q { norm_cdf(d1) } else { -norm_cdf(-d1) },
+        gamma: pdf / (f * s),
+        vega,
+        theta: -0.5 * f * pdf * sigma / sqrt_t,
+        vanna: -pdf * d2 / sigma,
+        volga: vega * d1 * d2 / sigma,
+    }
+}
+
+pub(crate) fn charm(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    // ∂d1/∂T = -d2 / (2T), and the delta of a put differs from that of a call by a constant.
+    -norm_pdf(d1) * d2 / (2.0 * t)
+}
+
+pub(crate) fn veta(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    f * norm_pdf(d1) * (1.0 + d1 * d2) / (2.0 * t.sqrt())
+}
+
+pub(crate) fn speed(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let s = sigma * t.sqrt();
+    let (d1, _) = d1_d2(f, k, sigma, t);
+    let gamma = norm_pdf(d1) / (f * s);
+    -gamma * (1.0 + d1 / s) / f
+}
+
+pub(crate) fn color(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    let gamma = norm_pdf(d1) / (f * sigma * t.sqrt());
+    gamma * (d1 * d2 - 1.0) / (2.0 * t)
+}
+
+pub(crate) fn ultima(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let sqrt_t = t.sqrt();
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    let vega = f * norm_pdf(d1) * sqrt_t;
+    let d1d2 = d1 * d2;
+    -vega * (d1d2 * (1.0 - d1d2) + d1 * d1 + d2 * d2) / (sigma * sigma)
+}
+
+pub(crate) fn zomma(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    let gamma = norm_pdf(d1) / (f * sigma * t.sqrt());
+    gamma * (d1 * d2 - 1.0) / sigma
+}
+
+pub(crate) fn shifted_greeks(
+    f: f64,
+    k: f64,
+    shift: f64,
+    sigma: f64,
+    t: f64,
+    q: bool,
+) -> BlackScholesGreeks {
+    greeks(f + shift, k + shift, sigma, t, q)
+}
+
+pub(crate) fn greeks_grid(
+    fs: &[f64],
+    ks: &[f64],
+    sigmas: &[f64],
+    ts: &[f64],
+    qs: &[bool],
+) -> Vec<BlackScholesGreeks> {
+    assert_eq!(ks.len(), fs.len());
+    assert_eq!(sigmas.len(), fs.len());
+    assert_eq!(ts.len(), fs.len());
+    assert_eq!(qs.len(), fs.len());
+    fs.iter()
+        .zip(ks)
+        .zip(sigmas)
+        .zip(ts)
+        .zip(qs)
+        .map(|((((&f, &k), &sigma), &t), &q)| greeks(f, k, sigma, t, q))
+        .collect()
+}
+
+pub(crate) fn vega_ladder(f: f64, t: f64, ks: &[f64], sigmas: &[f64]) -> Vec<f64> {
+    assert_eq!(sigmas.len(), ks.len());
+    let sqrt_f = f.sqrt();
+    let sqrt_t = t.sqrt();
+    ks.iter()
+        .zip(sigmas)
+        .map(|(&k, &sigma)| {
+            sqrt_f * k.sqrt() * sqrt_t * normalised_vega((f / k).ln(), sigma * sqrt_t)
+        })
+        .collect()
+}
+
+pub(crate) fn vega_weighted_mean_vol(f: f64, t: f64, ks: &[f64], sigmas: &[f64]) -> f64 {
+    let vegas = vega_ladder(f, t, ks, sigmas);
+    let total: f64 = vegas.iter().sum();
+    vegas
+        .iter()
+        .zip(sigmas)
+        .map(|(&vega, &sigma)| vega * sigma)
+        .sum::<f64>()
+        / total
+}
+
+pub(crate) fn vol_sensitivities(price: f64, f: f64, k: f64, t: f64, q: bool) -> Option<(f64, f64)> {
+    let sigma = implied_black_volatility(price, f, k, t, q);
+    if !sigma.is_finite() || sigma <= 0.0 {
+        return None;
+    }
+    let g = greeks(f, k, sigma, t, q);
+    // Differentiating price(sigma(price)) = price twice gives sigma' = 1 / vega and sigma'' = -volga sigma'^3.
+    let dsigma_dprice = g.vega.recip();
+    Some((dsigma_dprice, -g.volga * dsigma_dprice.powi(3)))
+}
+
+pub(crate) fn weighted_mid_vol(bid: f64, ask: f64, f: f64, k: f64, t: f64, q: bool) -> Option<f64> {
+    let solve = |price: f64| {
+        let sigma = implied_black_volatility(price, f, k, t, q);
+        (sigma.is_finite() && sigma > 0.0).then_some(sigma)
+    };
+    let (sigma_bid, sigma_ask) = (solve(bid)?, solve(ask)?);
+    let vega_bid = greeks(f, k, sigma_bid, t, q).vega;
+    let vega_ask = greeks(f, k, sigma_ask, t, q).vega;
+    let total = vega_bid + vega_ask;
+    if total <= 0.0 {
+        return None;
+    }
+    Some((vega_bid * sigma_bid + vega_ask * sigma_ask) / total)
+}
+
+pub(crate) fn payoff_decomposition(f: f64, k: f64, sigma: f64, t: f64) -> (f64, f64) {
+    let (d1, d2) = d1_d2(f, k, sigma, t);
+    (f * norm_cdf(d1), k * norm_cdf(d2))
+}
+
+pub(crate) fn implied_vol_for_target_delta(
+    delta: f64,
+    f: f64,
+    k: f64,
+    t: f64,
+    q: bool,
+) -> Option<f64> {
+    let d1 = if q {
+        inverse_norm_cdf(delta)
+    } else {
+        -inverse_norm_cdf(-delta)
+    };
+    // d1 = x / s + s / 2, so s solves s² - 2 d1 s + 2x = 0, whose roots have the product 2x.
+    let x = (f / k).ln();
+    let r = (d1 * d1 - 2.0 * x).sqrt();
+    let s = if x > 0.0 {
+        // Both roots are positive; the smaller one is on the branch where the delta falls in magnitude as s rises.
+        2.0 * x / (d1 + r)
+    } else if d1 > 0.0 {
+        d1 + r
+    } else {
+        2.0 * x / (d1 - r)
+    };
+    let sigma = s / t.sqrt();
+    (sigma > 0.0 && sigma.is_finite()).then_some(sigma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lets_be_rational::black;
+    use rand::Rng;
+
+    #[test]
+    fn d1_d2_atm() {
+        let (d1, d2) = d1_d2(100.0, 100.0, 0.2, 4.0);
+        assert_eq!(d1, 0.2);
+        assert_eq!(d2, -0.2);
+        assert_eq!(d1_d2(100.0, 100.0, 0.0, 1.0), (0.0, 0.0));
+        assert_eq!(d1_d2(100.0, 100.0, 0.2, 0.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn d1_d2_sign() {
+        let (d1, d2) = d1_d2(110.0, 100.0, 0.1, 1.0);
+        assert!(d1 > 0.0 && d2 > 0.0);
+        assert!((d1 - ((1.1_f64).ln() / 0.1 + 0.05)).abs() <= 4.0 * f64::EPSILON);
+        let (d1, d2) = d1_d2(90.0, 100.0, 0.1, 1.0);
+        assert!(d1 < 0.0 && d2 < 0.0);
+        assert_eq!(
+            d1_d2(110.0, 100.0, 0.0, 1.0),
+            (f64::INFINITY, f64::INFINITY)
+        );
+        assert_eq!(
+            d1_d2(90.0, 100.0, 0.0, 1.0),
+            (f64::NEG_INFINITY, f64::NEG_INFINITY)
+        );
+    }
+
+    #[test]
+    fn normalised_greeks_against_finite_differences() {
+        for (f, k, sigma, t) in [
+            (100.0, 100.0_f64, 0.2, 1.0_f64),
+            (100.0, 80.0, 0.3, 0.5),
+            (100.0, 130.0, 0.25, 2.0),
+            (1.0, 1.5, 0.8, 0.1),
+        ] {
+            let x = (f / k).ln();
+            let s = sigma * f64::sqrt(t);
+            for q in [true, false] {
+                let h = 1e-4 * f;
+                let delta =
+                    (black(f + h, k, sigma, t, q) - black(f - h, k, sigma, t, q)) / (2.0 * h);
+                assert!((normalised_delta(x, s, q) - delta).abs() < 1e-7);
+                let gamma = (black(f + h, k, sigma, t, q) - 2.0 * black(f, k, sigma, t, q)
+                    + black(f - h, k, sigma, t, q))
+                    / (h * h);
+                assert!((normalised_gamma(x, s) / f - gamma).abs() < 1e-5 * gamma.abs().max(1.0));
+                let h = 1e-6;
+                let vega =
+                    (black(f, k, sigma + h, t, q) - black(f, k, sigma - h, t, q)) / (2.0 * h);
+                let scale = (f * k).sqrt() * t.sqrt();
+                assert!((normalised_vega(x, s) * scale - vega).abs() < 1e-6 * vega.abs().max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn payoff_decomposition_reconstructs_call() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let f = 1.0;
+            let k = 2.0 * r;
+            let sigma = r2;
+            let t = 10.0 * r3;
+            let (asset, cash) = payoff_decomposition(f, k, sigma, t);
+            assert!(asset >= 0.0 && cash >= 0.0);
+            assert!((asset - cash - black(f, k, sigma, t, true)).abs() <= 4.0 * f64::EPSILON * f);
+        }
+    }
+
+    #[test]
+    fn per_percent_vega_and_per_day_theta() {
+        let (f, k, sigma, t) = (100.0, 110.0, 0.25, 0.5);
+        for q in [true, false] {
+            let g = greeks(f, k, sigma, t, q);
+            assert_eq!(g.vega_per_percent(), g.vega / 100.0);
+            assert_eq!(g.theta_per_day(), g.theta / 365.0);
+            // The scaled greeks predict the price changes over one volatility point and one day to first order.
+            let price = black(f, k, sigma, t, q);
+            let bumped = black(f, k, sigma + 0.01, t, q) - price;
+            assert!((bumped - g.vega_per_percent()).abs() <= 0.01 * g.vega_per_percent());
+            let decayed = black(f, k, sigma, t - 1.0 / 365.0, q) - price;
+            assert!((decayed - g.theta_per_day()).abs() <= 0.01 * g.theta_per_day().abs());
+        }
+    }
+
+    #[test]
+    fn target_delta_round_trip() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        let (f, t) = (100.0, 0.75);
+        for i in 0..10_000 {
+            let (r, r2): (f64, f64) = rng.gen();
+            let (k, sigma, q) = (50.0 + 100.0 * r, 0.05 + r2, i % 2 == 0);
+            let delta = greeks(f, k, sigma, t, q).delta;
+            if delta.abs() < 1e-10 || delta.abs() > 1.0 - 1e-10 {
+                continue;
+            }
+            let implied = implied_vol_for_target_delta(delta, f, k, t, q).unwrap();
+            let x = (f / k).ln();
+            if x <= 0.0 || sigma * t.sqrt() < (2.0 * x).sqrt() {
+                // Delta is known to about an ulp, which moves the volatility by that much over vanna, ∂Δ/∂σ.
+                let vanna = greeks(f, k, sigma, t, q).vanna;
+                let tolerance = 4.0 * f64::EPSILON * (sigma + 1.0 / vanna.abs());
+                assert!(
+                    (implied - sigma).abs() <= tolerance,
+                    "{k} {sigma} {q} {implied}"
+                );
+            }
+            let repriced = greeks(f, k, implied, t, q).delta;
+            assert!(
+                (repriced - delta).abs() <= 1e-14,
+                "{k} {sigma} {q} {delta} {repriced}"
+            );
+        }
+        // Below the forward, no volatility gives a call a delta under N(√(2x)).
+        assert_eq!(
+            implied_vol_for_target_delta(0.5, 100.0, 90.0, 1.0, true),
+            None
+        );
+        assert_eq!(
+            implied_vol_for_target_delta(1.5, 100.0, 90.0, 1.0, true),
+            None
+        );
+        assert_eq!(
+            implied_vol_for_target_delta(0.5, 100.0, 90.0, 1.0, false),
+            None
+        );
+        assert_eq!(
+            implied_vol_for_target_delta(0.4, 100.0, 100.0, 1.0, true),
+            None
+        );
+    }
+
+    #[test]
+    fn greeks_against_finite_differences() {
+        for (f, k, sigma, t) in [
+            (100.0, 100.0_f64, 0.2, 1.0_f64),
+            (100.0, 80.0, 0.3, 0.5),
+            (100.0, 130.0, 0.25, 2.0),
+            (1.0, 1.5, 0.8, 0.1),
+        ] {
+            for q in [true, false] {
+                let g = greeks(f, k, sigma, t, q);
+                let price = |f: f64, sigma: f64, t: f64| black(f, k, sigma, t, q);
+                let h = 1e-4 * f;
+                let delta = (price(f + h, sigma, t) - price(f - h, sigma, t)) / (2.0 * h);
+                assert!((g.delta - delta).abs() < 1e-7);
+                let gamma = (price(f + h, sigma, t) - 2.0 * price(f, sigma, t)
+                    + price(f - h, sigma, t))
+                    / (h * h);
+                assert!((g.gamma - gamma).abs() < 1e-5 * gamma.abs().max(1.0));
+                let h = 1e-5;
+                let vega = (price(f, sigma + h, t) - price(f, sigma - h, t)) / (2.0 * h);
+                assert!((g.vega - vega).abs() < 1e-6 * vega.abs().max(1.0));
+                let theta = -(price(f, sigma, t + h) - price(f, sigma, t - h)) / (2.0 * h);
+                assert!((g.theta - theta).abs() < 1e-6 * theta.abs().max(1.0));
+                let volga = (price(f, sigma + h, t) - 2.0 * price(f, sigma, t)
+                    + price(f, sigma - h, t))
+                    / (h * h);
+                assert!((g.volga - volga).abs() < 1e-3 * volga.abs().max(1.0));
+                let vanna = (greeks(f, k, sigma + h, t, q).delta
+                    - greeks(f, k, sigma - h, t, q).delta)
+                    / (2.0 * h);
+                assert!((g.vanna - vanna).abs() < 1e-6 * vanna.abs().max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn vol_sensitivities_against_finite_differences() {
+        for (f, k, sigma, t) in [
+            (100.0, 100.0_f64, 0.2, 1.0_f64),
+            (100.0, 80.0, 0.3, 0.5),
+            (100.0, 130.0, 0.25, 2.0),
+            (1.0, 1.5, 0.8, 0.1),
+        ] {
+            for q in [true, false] {
+                let price = black(f, k, sigma, t, q);
+                let (d1, d2) = vol_sensitivities(price, f, k, t, q).unwrap();
+                let vega = greeks(f, k, sigma, t, q).vega;
+                assert!((d1 * vega - 1.0).abs() <= 1e-12);
+                let h = 1e-3 * black(f, k, sigma, t, f < k);
+                let vol = |price: f64| implied_black_volatility(price, f, k, t, q);
+                let fd = (vol(price + h) - 2.0 * vol(price) + vol(price - h)) / (h * h);
+                assert!((d2 - fd).abs() < 1e-4 * fd.abs().max(1.0));
+            }
+        }
+        assert_eq!(vol_sensitivities(0.0, 100.0, 100.0, 1.0, true), None);
+        assert_eq!(vol_sensitivities(100.0, 100.0, 100.0, 1.0, true), None);
+    }
+
+    #[test]
+    fn weighted_mid_vol_lies_between_sides() {
+        for (f, k, t) in [
+            (100.0, 100.0_f64, 1.0_f64),
+            (100.0, 80.0, 0.5),
+            (100.0, 130.0, 2.0),
+            (1.0, 1.5, 0.1),
+        ] {
+            for q in [true, false] {
+                let (bid, ask) = (black(f, k, 0.2, t, q), black(f, k, 0.3, t, q));
+                let mid = weighted_mid_vol(bid, ask, f, k, t, q).unwrap();
+                assert!(mid > 0.2 && mid < 0.3);
+                let same = weighted_mid_vol(bid, bid, f, k, t, q).unwrap();
+                let sigma_bid = implied_black_volatility(bid, f, k, t, q);
+                assert!((same - sigma_bid).abs() <= 4.0 * f64::EPSILON * sigma_bid);
+            }
+        }
+        assert_eq!(weighted_mid_vol(0.0, 8.0, 100.0, 100.0, 1.0, true), None);
+        assert_eq!(weighted_mid_vol(8.0, 100.0, 100.0, 100.0, 1.0, true), None);
+    }
+
+    #[test]
+    fn charm_and_veta_against_mixed_finite_differences() {
+        for (f, k, sigma, t) in [
+            (100.0, 100.0_f64, 0.2, 1.0_f64),
+            (100.0, 80.0, 0.3, 0.5),
+            (100.0, 130.0, 0.25, 2.0),
+            (1.0, 1.5, 0.8, 0.1),
+        ] {
+            for q in [true, false] {
+                let price = |f: f64, sigma: f64, t: f64| black(f, k, sigma, t, q);
+                let (h, dt) = (1e-3 * f, 1e-4 * t);
+                let charm_fd = (price(f + h, sigma, t + dt)
+                    - price(f + h, sigma, t - dt)
+                    - price(f - h, sigma, t + dt)
+                    + price(f - h, sigma, t - dt))
+                    / (4.0 * h * dt);
+                let expected = charm(f, k, sigma, t);
+                assert!((expected - charm_fd).abs() < 1e-4 * expected.abs().max(1.0));
+                let ds = 1e-4;
+                let veta_fd = (price(f, sigma + ds, t + dt)
+                    - price(f, sigma + ds, t - dt)
+                    - price(f, sigma - ds, t + dt)
+                    + price(f, sigma - ds, t - dt))
+                    / (4.0 * ds * dt);
+                let expected = veta(f, k, sigma, t);
+                assert!((expected - veta_fd).abs() < 1e-4 * expected.abs().max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn speed_and_color_against_finite_differences() {
+        for (f, k, sigma, t) in [
+            (100.0, 100.0_f64, 0.2, 1.0_f64),
+            (100.0, 80.0, 0.3, 0.5),
+            (100.0, 130.0, 0.25, 2.0),
+            (1.0, 1.5, 0.8, 0.1),
+        ] {
+            for q in [true, false] {
+                let price = |f: f64, t: f64| black(f, k, sigma, t, q);
+                let h = 2e-3 * f;
+                let speed_fd = (price(f + 2.0 * h, t) - 2.0 * price(f + h, t)
+                    + 2.0 * price(f - h, t)
+                    - price(f - 2.0 * h, t))
+                    / (2.0 * h * h * h);
+                let expected = speed(f, k, sigma, t);
+                assert!((expected - speed_fd).abs() < 1e-3 * expected.abs());
+                let gamma =
+                    |t: f64| (price(f + h, t) - 2.0 * price(f, t) + price(f - h, t)) / (h * h);
+                let dt = 1e-3 * t;
+                let color_fd = (gamma(t + dt) - gamma(t - dt)) / (2.0 * dt);
+                let expected = color(f, k, sigma, t);
+                assert!((expected - color_fd).abs() < 1e-3 * expected.abs());
+            }
+        }
+    }
+
+    #[test]
+    fn ultima_and_zomma_reference_values() {
+        // References from differentiating the price symbolically in 50-digit arithmetic.
+        for (f, k, sigma, t, expected_ultima, expected_zomma) in [
+            (
+                100.0,
+                100.0,
+                0.2,
+                1.0,
+                -9.824_575_550_056_041,
+                -0.100_230_518_237_945_47,
+            ),
+            (
+                100.0,
+                80.0,
+                0.3,
+                0.5,
+                -341.664_771_484_383_83,
+                0.003_054_419_910_546_844_6,
+            ),
+            (
+                100.0,
+                130.0,
+                0.25,
+                2.0,
+                -1_087.533_589_074_770_6,
+                -0.018_487_527_107_341_566,
+            ),
+            (
+                1.0,
+                1.5,
+                0.8,
+                0.1,
+                -0.079_931_902_216_461_21,
+                1.029_456_114_980_02,
+            ),
+        ] {
+            let ultima = ultima(f, k, sigma, t);
+            assert!((ultima - expected_ultima).abs() <= 1e-12 * expected_ultima.abs());
+            let zomma = zomma(f, k, sigma, t);
+            assert!((zomma - expected_zomma).abs() <= 1e-12 * expected_zomma.abs());
+        }
+    }
+
+    #[test]
+    fn greeks_grid_matches_elements() {
+        let fs = [100.0, 100.0, 1.0, 50.0];
+        let ks = [100.0, 80.0, 1.5, 60.0];
+        let sigmas = [0.2, 0.3, 0.8, 0.4];
+        let ts = [1.0, 0.5, 0.1, 3.0];
+        let qs = [true, false, false, true];
+        let grid = greeks_grid(&fs, &ks, &sigmas, &ts, &qs);
+        assert_eq!(grid.len(), fs.len());
+        for i in 0..fs.len() {
+            assert_eq!(grid[i], greeks(fs[i], ks[i], sigmas[i], ts[i], qs[i]));
+        }
+        assert!(grid[0].delta > 0.0 && grid[1].delta < 0.0);
+    }
+
+    #[test]
+    fn vega_ladder_matches_points() {
+        let (f, t) = (100.0, 2.0);
+        let ks = [60.0, 80.0, 100.0, 120.0, 150.0];
+        let sigmas = [0.35, 0.28, 0.22, 0.2, 0.21];
+        let ladder = vega_ladder(f, t, &ks, &sigmas);
+        assert_eq!(ladder.len(), ks.len());
+        for ((&k, &sigma), &vega) in ks.iter().zip(&sigmas).zip(&ladder) {
+            let point = greeks(f, k, sigma, t, true).vega;
+            assert!((vega - point).abs() <= 1e-13 * point);
+            let h = 1e-5;
+            let fd =
+                (black(f, k, sigma + h, t, false) - black(f, k, sigma - h, t, false)) / (2.0 * h);
+            assert!((vega - fd).abs() < 1e-6 * vega.max(1.0));
+        }
+    }
+
+    #[test]
+    fn vega_weighted_mean_vol_two_points() {
+        // The vegas are 34.36134321916172 at (90, 25%) and 37.16384436594333 at (110, 20%).
+        let mean = vega_weighted_mean_vol(100.0, 1.0, &[90.0, 110.0], &[0.25, 0.2]);
+        assert!((mean - 0.22402044956420176).abs() <= 1e-15);
+        assert_eq!(vega_weighted_mean_vol(100.0, 1.0, &[90.0], &[0.25]), 0.25);
+        assert!(vega_weighted_mean_vol(100.0, 1.0, &[], &[]).is_nan());
+    }
+
+    #[test]
+    fn shifted_greeks_against_finite_differences() {
+        let (k, shift, sigma, t) = (0.01, 0.03, 0.25, 2.0);
+        for f in [-0.01, 0.0, 0.02] {
+            for q in [true, false] {
+                let g = shifted_greeks(f, k, shift, sigma, t, q);
+                let price = |f: f64, sigma: f64| black(f + shift, k + shift, sigma, t, q);
+                let h = 1e-5;
+                let delta = (price(f + h, sigma) - price(f - h, sigma)) / (2.0 * h);
+                assert!((g.delta - delta).abs() < 1e-7);
+                let h = 1e-4;
+                let gamma =
+                    (price(f + h, sigma) - 2.0 * price(f, sigma) + price(f - h, sigma)) / (h * h);
+                assert!((g.gamma - gamma).abs() < 1e-4 * gamma.abs().max(1.0));
+                let h = 1e-6;
+                let vega = (price(f, sigma + h) - price(f, sigma - h)) / (2.0 * h);
+                assert!((g.vega - vega).abs() < 1e-8);
+            }
+        }
+    }
+}
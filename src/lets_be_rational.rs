@@ -11,7 +11,7 @@ fn householder3_factor(v: f64, h2: f64, h3: f64) -> f64 { (1.0 + 0.5 * h2 * v) /
 #[inline]
 fn householder4_factor(v: f64, h2: f64, h3: f64, h4: f64) -> f64 { (1.0 + v * (h2 + v * h3 / 6.0)) / (1.0 + v * (1.5 * h2 + v * (h2 * h2 / 4.0 + h3 / 3.0 + v * h4 / 24.0))) }
 
-fn normalised_intrinsic(x: f64, q: bool) -> f64 {
+pub(crate) fn normalised_intrinsic(x: f64, q: bool) -> f64 {
     if (q && !x.is_sign_positive()) || (!q && !x.is_sign_negative()) {
         return 0.0;
     }
@@ -26,12 +26,8 @@ fn normalised_intrinsic(x: f64, q: bool) -> f64 {
     }
     let b_max = (0.5 * x).exp();
     let one_over_b_max = b_max.recip();
-    let ret = (b_max - one_over_b_max).abs().max(0.0);
-    if q {
-        ret
-    } else {
-        -ret
-    }
+    // The option is in the money here, so its intrinsic value is |b_max - 1 / b_max| for calls and puts alike.
+    (b_max - one_over_b_max).abs()
 }
 
 #[inline]
@@ -48,6 +44,9 @@ const ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD: f64 = -10.0;
 const SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD: f64 = 2.0 * SIXTEENTH_ROOT_DBL_EPSILON;
 
 fn asymptotic_expansion_of_normalised_black_call_over_vega(h: f64, t: f64) -> f64 {
+    #[cfg(feature = "no-runtime-checks")]
+    debug_assert!((h < -ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD.abs()) && (h + t < -(SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD + ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD).abs()));
+    #[cfg(not(feature = "no-runtime-checks"))]
     assert!((h < -ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD.abs()) && (h + t < -(SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD + ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD).abs()));
     let e = square(t / h);
     let r = (h + t) * (h - t);
@@ -88,7 +87,7 @@ fn normalised_black_call_with_optimal_use_of_codys_functions(x: f64, s: f64) ->
     two_b.abs().max(0.0)
 }
 
-fn normalised_vega(x: f64, s: f64) -> f64 {
+pub(crate) fn normalised_vega(x: f64, s: f64) -> f64 {
     let ax = x.abs();
     if ax <= 0.0 {
         (1.0 / SQRT_TWO_PI) * (-0.125 * s * s).exp()
@@ -99,6 +98,17 @@ fn normalised_vega(x: f64, s: f64) -> f64 {
     }
 }
 
+pub(crate) fn inv_normalised_vega(x: f64, s: f64) -> f64 {
+    let ax = x.abs();
+    if ax <= 0.0 {
+        SQRT_TWO_PI * (0.125 * s * s).exp()
+    } else if s <= 0.0 || s <= ax * SQRT_MIN_POSITIVE {
+        f64::INFINITY
+    } else {
+        SQRT_TWO_PI * (0.5 * (square(x / s) + square(0.5 * s))).exp()
+    }
+}
+
 fn ln_normalised_vega(x: f64, s: f64) -> f64 {
     let ax = x.abs();
     if ax <= 0.0 {
@@ -110,39 +120,74 @@ fn ln_normalised_vega(x: f64, s: f64) -> f64 {
     }
 }
 
+/// The method by which the normalised black price is evaluated at a point `(x, s)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// The total volatility is so small relative to the moneyness that the time value underflows, and the price is
+    /// its intrinsic value.
+    Intrinsic,
+    /// Far out of the money relative to the total volatility, where an asymptotic expansion is used.
+    Asymptotic,
+    /// Small total volatility, where a Taylor expansion in `s` is used.
+    SmallT,
+    /// Everywhere else, where the price is evaluated with Cody's error functions.
+    Cody,
+}
+
+/// Selects the evaluation method of the normalised black price, which depends on the out-of-the-money side only.
+pub(crate) fn region(x: f64, s: f64) -> Region {
+    let x = -x.abs();
+    if s <= x.abs() * DENORMALISATION_CUTOFF {
+        Region::Intrinsic
+    } else if x < s * ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD && 0.5 * s * s + x < s * (SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD + ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD) {
+        Region::Asymptotic
+    } else if 0.5 * s < SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD {
+        Region::SmallT
+    } else {
+        Region::Cody
+    }
+}
+
 fn normalised_black_call(x: f64, s: f64) -> f64 {
     if x.is_sign_positive() {
         return normalised_intrinsic_call(x) + normalised_black_call(-x, s);
     }
-    if s <= x.abs() * DENORMALISATION_CUTOFF {
-        return normalised_intrinsic_call(x);
-    }
-    if x < s * ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD && square(0.5 * s) + x < s * (SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD + ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD) {
-        return asymptotic_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s) * normalised_vega(x, s);
-    }
-    if 0.5 * s < SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD {
-        return small_t_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s) * normalised_vega(x, s);
+    match region(x, s) {
+        Region::Intrinsic => normalised_intrinsic_call(x),
+        Region::Asymptotic => asymptotic_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s) * normalised_vega(x, s),
+        Region::SmallT => small_t_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s) * normalised_vega(x, s),
+        Region::Cody => normalised_black_call_with_optimal_use_of_codys_functions(x, s),
     }
-    normalised_black_call_with_optimal_use_of_codys_functions(x, s)
 }
 
-
-fn normalised_black_call_over_vega_and_ln_vega(x: f64, s: f64) -> (f64, f64) {
+pub(crate) fn normalised_black_call_over_vega_and_ln_vega(x: f64, s: f64) -> (f64, f64) {
     if x.is_sign_positive() {
         let (bx, ln_vega) = normalised_black_call_over_vega_and_ln_vega(-x, s);
         return (normalised_intrinsic_call(x) * (-ln_vega).exp() + bx, ln_vega);
     }
     let ln_vega = ln_normalised_vega(x, s);
-    if s <= x.abs() * DENORMALISATION_CUTOFF {
-        return (normalised_intrinsic_call(x) * (-ln_vega).exp(), ln_vega);
-    }
-    if x < s * ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD && 0.5 * s * s + x < s * (SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD + ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD) {
-        return (asymptotic_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s), ln_vega);
-    }
-    if 0.5 * s < SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD {
-        return (small_t_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s), ln_vega);
-    }
-    (normalised_black_call_with_optimal_use_of_codys_functions(x, s) * (-ln_vega).exp(), ln_vega)
+    let bx = match region(x, s) {
+        Region::Intrinsic => normalised_intrinsic_call(x) * (-ln_vega).exp(),
+        Region::Asymptotic => asymptotic_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s),
+        Region::SmallT => small_t_expansion_of_normalised_black_call_over_vega(x / s, 0.5 * s),
+        Region::Cody => normalised_black_call_with_optimal_use_of_codys_functions(x, s) * (-ln_vega).exp(),
+    };
+    (bx, ln_vega)
+}
+
+pub(crate) fn normalised_black_and_vega(x: f64, s: f64, q: bool) -> (f64, f64) {
+    let x = if !q { -x } else { x };
+    let vega = normalised_vega(x, s);
+    // The same branches as normalised_black_call, on the out-of-the-money side, reusing the vega.
+    let otm_x = -x.abs();
+    let otm = match region(otm_x, s) {
+        Region::Intrinsic => 0.0,
+        Region::Asymptotic => asymptotic_expansion_of_normalised_black_call_over_vega(otm_x / s, 0.5 * s) * vega,
+        Region::SmallT => small_t_expansion_of_normalised_black_call_over_vega(otm_x / s, 0.5 * s) * vega,
+        Region::Cody => normalised_black_call_with_optimal_use_of_codys_functions(otm_x, s),
+    };
+    let price = if x.is_sign_positive() { normalised_intrinsic_call(x) + otm } else { otm };
+    (price, vega)
 }
 
 #[inline]
@@ -151,11 +196,45 @@ fn normalised_black(x: f64, s: f64, theta: bool) -> f64 {
 }
 
 pub(crate) fn black(f: f64, k: f64, sigma: f64, t: f64, q: bool) -> f64 {
+    black_sqrt_expiry(f, k, sigma, t.sqrt(), q)
+}
+
+#[inline]
+pub(crate) fn black_sqrt_expiry(f: f64, k: f64, sigma: f64, sqrt_t: f64, q: bool) -> f64 {
+    black_from_s(f, k, sigma * sqrt_t, q)
+}
+
+#[inline]
+pub(crate) fn black_const<const Q: bool>(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+    black_from_s_const::<Q>(f, k, sigma * t.sqrt())
+}
+
+pub(crate) fn black_from_s(f: f64, k: f64, s: f64, q: bool) -> f64 {
+    if q { black_from_s_const::<true>(f, k, s) } else { black_from_s_const::<false>(f, k, s) }
+}
+
+/// [`black_from_s`] with the option type as a const parameter, so that each instantiation prices one option type.
+fn black_from_s_const<const Q: bool>(f: f64, k: f64, s: f64) -> f64 {
+    let intrinsic = if !Q { k - f } else { f - k }.max(0f64).abs();
+    if (Q && ((f - k).is_sign_positive())) || (!Q && ((f - k).is_sign_negative())) {
+        // The out-of-the-money counterpart is priced by the other instantiation.
+        return intrinsic + if Q { black_from_s_const::<false>(f, k, s) } else { black_from_s_const::<true>(f, k, s) };
+    }
+    let x = (f / k).ln();
+    intrinsic.max((f.sqrt() * k.sqrt()) * normalised_black_call(if !Q { -x } else { x }, s))
+}
+
+pub(crate) fn black_price_curve(f: f64, k: f64, t: f64, q: bool, sigmas: &[f64], out: &mut [f64]) {
+    assert_eq!(sigmas.len(), out.len());
     let intrinsic = if !q { k - f } else { f - k }.max(0f64).abs();
-    if (q && ((f - k).is_sign_positive())) || (!q && ((f - k).is_sign_negative())) {
-        return intrinsic + black(f, k, sigma, t, !q);
+    // As in `black_from_s`, in-the-money options are priced as intrinsic value plus the out-of-the-money counterpart.
+    let q_otm = if (q && ((f - k).is_sign_positive())) || (!q && ((f - k).is_sign_negative())) { !q } else { q };
+    let scale = f.sqrt() * k.sqrt();
+    let x = (f / k).ln();
+    let sqrt_t = t.sqrt();
+    for (o, &sigma) in out.iter_mut().zip(sigmas) {
+        *o = intrinsic + 0f64.max(scale * normalised_black(x, sigma * sqrt_t, q_otm));
     }
-    intrinsic.max((f.sqrt() * k.sqrt()) * normalised_black((f / k).ln(), sigma * t.sqrt(), q))
 }
 
 fn compute_f_lower_map_and_first_two_derivatives(x: f64, s: f64) -> (f64, f64, f64) {
@@ -216,21 +295,69 @@ fn take_step(x_min: f64, x_max: f64, x: f64, dx: f64) -> (f64, f64) {
     (new_x, new_x - x)
 }
 
-fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
-    mut beta: f64, mut x: f64, q: bool, n: u8,
-) -> f64 {
-    if (q && (x.is_sign_positive())) || (!q && (x.is_sign_negative())) {
-        beta = (beta - normalised_intrinsic(x, q)).max(0.).abs();
+/// How the refinement of an implied volatility ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Convergence {
+    /// The last step was small enough for the result to be accurate to machine precision.
+    Converged,
+    /// The iteration cap was hit while the steps were still significant.
+    MaxIterationsReached,
+    /// The Householder iteration diverged, and the volatility was found by bisection instead, to the last bit.
+    BisectionFallback {
+        /// The number of bisection steps taken, including the doublings of the initial bracket.
+        iterations: u32,
+    },
+}
+
+/// Information about how an implied volatility was obtained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// The number of Householder steps taken.
+    pub iterations: u8,
+    /// How the refinement ended.
+    pub convergence: Convergence,
+}
+
+impl Diagnostics {
+    const EXACT: Self = Self { iterations: 0, convergence: Convergence::Converged };
+
+    fn from_last_step(iterations: u8, ds: f64, s: f64) -> Self {
+        // The steps are of third or fourth order, so the error left after a step of relative size
+        // EPSILON^(1/3) is of the order of EPSILON.
+        let convergence = if ds.abs() <= f64::EPSILON.cbrt() * s { Convergence::Converged } else { Convergence::MaxIterationsReached };
+        Self { iterations, convergence }
+    }
+}
+
+pub(crate) fn normalised_band(x: f64) -> (f64, f64, f64) {
+    let x = -x.abs();
+    let b_max = (0.5 * x).exp();
+    let s_c = SQRT_2 * x.abs().sqrt();
+    let b_c = normalised_black_call(x, s_c);
+    let v_c = normalised_vega(x, s_c);
+    let s_l = s_c - b_c / v_c;
+    let s_u = if v_c > f64::MIN_POSITIVE { s_c + (b_max - b_c) / v_c } else { s_c };
+    (normalised_black_call(x, s_l), b_c, normalised_black_call(x, s_u))
+}
+
+fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations<const Q: bool>(
+    mut beta: f64, mut x: f64, n: u8, initial_guess: Option<f64>, step_tolerance: f64, observe: &mut impl FnMut(f64),
+) -> (f64, Diagnostics) {
+    if (Q && (x.is_sign_positive())) || (!Q && (x.is_sign_negative())) {
+        beta = (beta - normalised_intrinsic(x, Q)).max(0.).abs();
     }
-    if !q {
+    if !Q {
         x = -x;
     }
     if beta <= 0. || beta < DENORMALISATION_CUTOFF {
-        return 0.0;
+        return (0.0, Diagnostics::EXACT);
     }
     let b_max = (0.5 * x).exp();
     if beta >= b_max {
-        return VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
+        return (VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, Diagnostics::EXACT);
+    }
+    if let Some(s) = initial_guess {
+        return householder3_iteration(beta, x, s, f64::MIN_POSITIVE, f64::MAX, n, step_tolerance, observe);
     }
     let mut iterations = 0;
     let mut f = f64::MIN;
@@ -255,9 +382,10 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
             s = inverse_f_lower_map(x, f);
             s_right = s1;
             let ln_beta = beta.ln();
+            observe(s);
 
             ds = 1.0_f64;
-            while iterations < n && ds.abs() > f64::EPSILON * s {
+            while iterations < n && ds.abs() > step_tolerance * s {
                 let (bx, ln_vega) = normalised_black_call_over_vega_and_ln_vega(x, s);
                 let ln_b = bx.ln() + ln_vega;
                 let bpob = 1.0 / bx;
@@ -278,9 +406,10 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
                     nu * householder3_factor(nu, h2, h3)
                 };
                 (s, ds) = take_step(s_left, s_right, s, ds);
+                observe(s);
                 iterations += 1;
             }
-            return s;
+            return (s, Diagnostics::from_last_step(iterations, ds, s));
         } else {
             let v1 = normalised_vega(x, s1);
             let r_im = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(b1, b_c, s1, s_c, v1.recip(), v_c.recip(), 0.0, false);
@@ -313,7 +442,8 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
             (s, s_left) = (inverse_f_upper_map(f), s_u);
             if beta > 0.5 * b_max {
                 let beta_bar = b_max - beta;
-                while iterations < n && ds.abs() > f64::EPSILON * s {
+                observe(s);
+                while iterations < n && ds.abs() > step_tolerance * s {
                     let h = x / s;
                     let t = s / 2.0;
                     let gp = SQRT_TWO_OVER_PI / (erfcx_cody((t + h) * FRAC_1_SQRT_2) + erfcx_cody((t - h) * FRAC_1_SQRT_2));
@@ -332,14 +462,25 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
                         nu * householder3_factor(nu, h2, h3)
                     };
                     (s, ds) = take_step(s_left, s_right, s, ds);
+                    observe(s);
                     iterations += 1;
                 }
-                return s;
+                return (s, Diagnostics::from_last_step(iterations, ds, s));
             }
         }
     }
-    for _ in 0..n {
-        if ds.abs() <= f64::EPSILON * s {
+    householder3_iteration(beta, x, s, s_left, s_right, n, step_tolerance, observe)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn householder3_iteration(
+    beta: f64, x: f64, mut s: f64, s_left: f64, s_right: f64, n: u8, step_tolerance: f64, observe: &mut impl FnMut(f64),
+) -> (f64, Diagnostics) {
+    let mut iterations = 0;
+    let mut ds = f64::MIN;
+    observe(s);
+    while iterations < n {
+        if ds.abs() <= step_tolerance * s {
             break;
         }
 
@@ -352,47 +493,547 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
         ds = nu * householder3_factor(nu, h2, h3);
         // Never leave the branch (or bracket)
         (s, ds) = take_step(s_left, s_right, s, ds);
+        observe(s);
+        iterations += 1;
     }
-    s
+    (s, Diagnostics::from_last_step(iterations, ds, s))
 }
 
-fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
-    mut price: f64,
+const INTRINSIC_VALUE_TOLERANCE_IN_ULPS: u64 = 4;
+
+#[inline]
+fn intrinsic_value(f: f64, k: f64, q: bool) -> f64 {
+    (if !q { k - f } else { f - k }).max(0.0).abs()
+}
+
+pub(crate) fn intrinsic_and_time_value(price: f64, f: f64, k: f64, q: bool) -> (f64, f64) {
+    let intrinsic = intrinsic_value(f, k, q);
+    (intrinsic, price - intrinsic)
+}
+
+fn implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+    price: f64,
     f: f64,
+    sqrt_f: f64,
     k: f64,
-    t: f64,
-    mut q: bool,
+    q: bool,
+    n: u8,
+    initial_guess: Option<f64>,
+) -> (f64, Diagnostics) {
+    observed_implied_normalised_volatility(price, f, sqrt_f, k, q, n, initial_guess, f64::EPSILON, &mut |_| {})
+}
+
+/// Like [`implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations`], passing the
+/// initial guess and every iterate of the normalised volatility to `observe`, and stopping the Householder iteration
+/// once a step is at most `step_tolerance` relative to the iterate.
+#[allow(clippy::too_many_arguments)]
+fn observed_implied_normalised_volatility(
+    price: f64,
+    f: f64,
+    sqrt_f: f64,
+    k: f64,
+    q: bool,
     n: u8,
-) -> f64 {
-    let intrinsic = (if !q { k - f } else { f - k }).max(0.0).abs();
+    initial_guess: Option<f64>,
+    step_tolerance: f64,
+    observe: &mut impl FnMut(f64),
+) -> (f64, Diagnostics) {
+    if q {
+        observed_implied_normalised_volatility_const::<true>(price, f, sqrt_f, k, n, initial_guess, step_tolerance, observe)
+    } else {
+        observed_implied_normalised_volatility_const::<false>(price, f, sqrt_f, k, n, initial_guess, step_tolerance, observe)
+    }
+}
+
+/// [`observed_implied_normalised_volatility`] with the option type as a const parameter, so that each instantiation
+/// solves for one option type.
+#[allow(clippy::too_many_arguments)]
+fn observed_implied_normalised_volatility_const<const Q: bool>(
+    price: f64,
+    f: f64,
+    sqrt_f: f64,
+    k: f64,
+    n: u8,
+    initial_guess: Option<f64>,
+    step_tolerance: f64,
+    observe: &mut impl FnMut(f64),
+) -> (f64, Diagnostics) {
+    let intrinsic = intrinsic_value(f, k, Q);
     if price < intrinsic {
+        // A price a few ulps below intrinsic is most likely the intrinsic value itself, rounded. Both are non-negative
+        // here, so the difference of their bit patterns counts the doubles between them.
+        if price >= 0.0 && intrinsic.to_bits() - price.abs().to_bits() <= INTRINSIC_VALUE_TOLERANCE_IN_ULPS {
+            return (0.0, Diagnostics::EXACT);
+        }
         return
-            VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC;
+            (VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC, Diagnostics::EXACT);
     }
-    let max_price = if !q { k } else { f };
+    let max_price = if !Q { k } else { f };
     if price >= max_price {
         return
-            VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
+            (VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, Diagnostics::EXACT);
     }
     let x = (f / k).ln();
-    // Map in-the-money to out-of-the-money
-    if (q && (x.is_sign_positive())) || (!q && (x.is_sign_negative())) {
-        price = (price - intrinsic).max(0.0).abs();
-        q = !q;
+    // Map in-the-money to out-of-the-money, which the other instantiation solves for
+    if (Q && (x.is_sign_positive())) || (!Q && (x.is_sign_negative())) {
+        let price = (price - intrinsic).max(0.0).abs();
+        return if Q {
+            out_of_the_money_normalised_volatility::<false>(price, f, sqrt_f, k, x, n, initial_guess, step_tolerance, observe)
+        } else {
+            out_of_the_money_normalised_volatility::<true>(price, f, sqrt_f, k, x, n, initial_guess, step_tolerance, observe)
+        };
     }
+    out_of_the_money_normalised_volatility::<Q>(price, f, sqrt_f, k, x, n, initial_guess, step_tolerance, observe)
+}
 
-    unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
-        price / ((f * k).sqrt()),
+#[allow(clippy::too_many_arguments)]
+fn out_of_the_money_normalised_volatility<const Q: bool>(
+    price: f64,
+    f: f64,
+    sqrt_f: f64,
+    k: f64,
+    x: f64,
+    n: u8,
+    initial_guess: Option<f64>,
+    step_tolerance: f64,
+    observe: &mut impl FnMut(f64),
+) -> (f64, Diagnostics) {
+    let (s, diagnostics) = unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations::<Q>(
+        price / (sqrt_f * k.sqrt()),
         x,
-        q,
         n,
-    ) / t.sqrt()
+        initial_guess,
+        step_tolerance,
+        observe,
+    );
+    if s.is_nan() {
+        // The Householder iteration diverged; fall back to the slow but guaranteed bisection.
+        let (s, iterations) = normalised_implied_volatility_by_bisection::<Q>(price, f, k);
+        observe(s);
+        return (s, Diagnostics { convergence: Convergence::BisectionFallback { iterations }, ..diagnostics });
+    }
+    (s, diagnostics)
+}
+
+/// Returns the normalised volatility together with the number of steps taken.
+fn normalised_implied_volatility_by_bisection<const Q: bool>(price: f64, f: f64, k: f64) -> (f64, u32) {
+    let mut s_left = 0.0;
+    let mut s_right = 1.0;
+    let mut iterations = 0;
+    while black_from_s_const::<Q>(f, k, s_right) < price {
+        s_left = s_right;
+        s_right *= 2.0;
+        iterations += 1;
+        if s_right.is_infinite() {
+            return (VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, iterations);
+        }
+    }
+    loop {
+        let s_mid = 0.5 * (s_left + s_right);
+        if s_mid <= s_left || s_mid >= s_right {
+            return (s_mid, iterations);
+        }
+        iterations += 1;
+        if black_from_s_const::<Q>(f, k, s_mid) < price {
+            s_left = s_mid;
+        } else {
+            s_right = s_mid;
+        }
+    }
+}
+
+pub(crate) fn implied_volatility_by_inversion<F: Fn(f64) -> f64>(
+    price: f64,
+    price_fn: F,
+    lower: f64,
+    upper: f64,
+) -> Option<f64> {
+    // An infinite end would make every secant step NaN and every midpoint infinite.
+    if price.is_nan() || !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        return None;
+    }
+    let (mut s_left, mut s_right) = (lower, upper);
+    let (mut d_left, mut d_right) = (price_fn(s_left) - price, price_fn(s_right) - price);
+    if d_left.is_nan() || d_right.is_nan() || d_left > 0.0 || d_right < 0.0 {
+        return None;
+    }
+    if d_left == 0.0 {
+        return Some(s_left);
+    }
+    if d_right == 0.0 {
+        return Some(s_right);
+    }
+    // Regula falsi with the Illinois modification: an endpoint retained twice in a row has its residual halved, which
+    // restores superlinear convergence without needing the derivative of the price function.
+    let mut retained = 0_i8;
+    loop {
+        let mut s = s_right - d_right * (s_right - s_left) / (d_right - d_left);
+        if s.is_nan() || s <= s_left || s >= s_right {
+            s = 0.5 * (s_left + s_right);
+            if s <= s_left || s >= s_right {
+                return Some(s);
+            }
+        }
+        let d = price_fn(s) - price;
+        if d == 0.0 || d.is_nan() {
+            return (d == 0.0).then_some(s);
+        }
+        if d < 0.0 {
+            (s_left, d_left) = (s, d);
+            if retained == 1 {
+                d_right *= 0.5;
+            }
+            retained = 1;
+        } else {
+            (s_right, d_right) = (s, d);
+            if retained == -1 {
+                d_left *= 0.5;
+            }
+            retained = -1;
+        }
+        if s_right - s_left <= f64::EPSILON * s_right.abs() {
+            return Some(0.5 * (s_left + s_right));
+        }
+    }
+}
+
+fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+    price: f64,
+    f: f64,
+    k: f64,
+    t: f64,
+    q: bool,
+    n: u8,
+) -> (f64, Diagnostics) {
+    let (s, diagnostics) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f.sqrt(), k, q, n, None);
+    (normalised_to_volatility(s, t.sqrt()), diagnostics)
+}
+
+#[inline]
+fn normalised_to_volatility(s: f64, sqrt_t: f64) -> f64 {
+    if s.is_infinite() {
+        return s;
+    }
+    if sqrt_t == 0.0 && !s.is_nan() {
+        // At zero expiry only the intrinsic value is attainable, and any volatility, in particular zero, reproduces it.
+        return if s == 0.0 { 0.0 } else { VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM };
+    }
+    s / sqrt_t
+}
+
+pub(crate) fn implied_normalised_volatility_atm(beta: f64) -> f64 {
+    if beta < 0.0 {
+        return VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC;
+    }
+    if beta >= 1.0 {
+        return VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
+    }
+    if beta <= 0.0 {
+        return 0.0;
+    }
+    // At the money, beta = 2Φ(s/2) - 1. The closed form loses relative accuracy for small beta, which the Newton steps
+    // on the accurate normalised price recover.
+    let mut s = -2.0 * inverse_norm_cdf(0.5 * (1.0 - beta));
+    for _ in 0..4 {
+        let ds = (beta - normalised_black_call(0.0, s)) / normalised_vega(0.0, s);
+        s += ds;
+        if ds.abs() <= f64::EPSILON * s {
+            break;
+        }
+    }
+    s
+}
+
+pub(crate) fn implied_normalised_black_volatility(price: f64, f: f64, k: f64, q: bool) -> f64 {
+    implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f.sqrt(), k, q, 2, None).0
+}
+
+pub(crate) fn implied_black_volatility_smile(f: f64, t: f64, strikes: &[f64], prices: &[f64], qs: &[bool], out: &mut [f64]) {
+    assert_eq!(strikes.len(), out.len());
+    assert_eq!(prices.len(), out.len());
+    assert_eq!(qs.len(), out.len());
+    let sqrt_f = f.sqrt();
+    let sqrt_t = t.sqrt();
+    for (((o, &k), &price), &q) in out.iter_mut().zip(strikes).zip(prices).zip(qs) {
+        let (s, _) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, sqrt_f, k, q, 2, None);
+        *o = normalised_to_volatility(s, sqrt_t);
+    }
+}
+
+pub(crate) fn implied_black_volatility_slice_tol(prices: &[f64], fs: &[f64], ks: &[f64], ts: &[f64], qs: &[bool], rel_tol: f64, out: &mut [f64]) {
+    assert_eq!(prices.len(), out.len());
+    assert_eq!(fs.len(), out.len());
+    assert_eq!(ks.len(), out.len());
+    assert_eq!(ts.len(), out.len());
+    assert_eq!(qs.len(), out.len());
+    // The steps are of third or fourth order, so a step of relative size rel_tol^(1/3) leaves an error of the order
+    // of rel_tol. Asking for less than machine precision falls back to the full-precision stopping rule.
+    let step_tolerance = if rel_tol > f64::EPSILON { rel_tol.cbrt() } else { f64::EPSILON };
+    for (((((o, &price), &f), &k), &t), &q) in out.iter_mut().zip(prices).zip(fs).zip(ks).zip(ts).zip(qs) {
+        let (s, _) = observed_implied_normalised_volatility(price, f, f.sqrt(), k, q, 2, None, step_tolerance, &mut |_| {});
+        *o = normalised_to_volatility(s, t.sqrt());
+    }
 }
 
 pub(crate) fn implied_black_volatility(price: f64, f: f64, k: f64, t: f64, q: bool) -> f64 {
-    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, 2)
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, 2).0
+}
+
+pub(crate) fn implied_black_volatility_const<const Q: bool>(price: f64, f: f64, k: f64, t: f64) -> f64 {
+    let (s, _) = observed_implied_normalised_volatility_const::<Q>(price, f, f.sqrt(), k, 2, None, f64::EPSILON, &mut |_| {});
+    normalised_to_volatility(s, t.sqrt())
+}
+
+pub(crate) fn implied_black_volatility_sqrt_expiry(price: f64, f: f64, k: f64, sqrt_t: f64, q: bool) -> f64 {
+    let (s, _) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f.sqrt(), k, q, 2, None);
+    normalised_to_volatility(s, sqrt_t)
+}
+
+#[inline]
+pub(crate) fn implied_black_volatility_array<const N: usize>(prices: [f64; N], fs: [f64; N], ks: [f64; N], ts: [f64; N], qs: [bool; N]) -> [f64; N] {
+    std::array::from_fn(|i| implied_black_volatility(prices[i], fs[i], ks[i], ts[i], qs[i]))
+}
+
+pub(crate) fn implied_black_volatility_traced(price: f64, f: f64, k: f64, t: f64, q: bool) -> Option<(f64, Vec<f64>)> {
+    let sqrt_t = t.sqrt();
+    let mut trace = Vec::new();
+    let (s, _) = observed_implied_normalised_volatility(price, f, f.sqrt(), k, q, 2, None, f64::EPSILON, &mut |s| trace.push(normalised_to_volatility(s, sqrt_t)));
+    let sigma = normalised_to_volatility(s, sqrt_t);
+    sigma.is_finite().then_some((sigma, trace))
+}
+
+pub(crate) fn black_vol_initial_guess(price: f64, f: f64, k: f64, t: f64, q: bool) -> Option<f64> {
+    let (s, _) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f.sqrt(), k, q, 0, None);
+    let sigma = normalised_to_volatility(s, t.sqrt());
+    sigma.is_finite().then_some(sigma)
+}
+
+pub(crate) fn implied_black_volatility_and_vega(price: f64, f: f64, k: f64, t: f64, q: bool) -> Option<(f64, f64)> {
+    let sqrt_f = f.sqrt();
+    let sqrt_t = t.sqrt();
+    let (s, _) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, sqrt_f, k, q, 2, None);
+    let sigma = normalised_to_volatility(s, sqrt_t);
+    // The price is sqrt(f k) b(x, σ√t), so its derivative in σ is sqrt(f k t) times the normalised vega at the solution.
+    sigma.is_finite().then(|| (sigma, sqrt_f * k.sqrt() * sqrt_t * normalised_vega((f / k).ln(), s)))
+}
+
+pub(crate) fn implied_black_volatility_from_straddle(price: f64, f: f64, k: f64, t: f64) -> Option<f64> {
+    // A straddle is worth twice its out-of-the-money leg plus the intrinsic value of the other, so only the
+    // out-of-the-money leg, whose price carries the whole time value, needs to be inverted.
+    let out_of_the_money = 0.5 * (price - (f - k).abs());
+    implied_black_volatility_checked(out_of_the_money, f, k, t, k > f).ok()
+}
+
+pub(crate) fn implied_black_volatility_median(prices: &[f64], f: f64, k: f64, t: f64, q: bool) -> Option<f64> {
+    let mut vols: Vec<f64> = prices.iter().filter_map(|&price| implied_black_volatility_checked(price, f, k, t, q).ok()).collect();
+    let n = vols.len();
+    if n == 0 {
+        return None;
+    }
+    let (lower, &mut upper, _) = vols.select_nth_unstable_by(n / 2, f64::total_cmp);
+    if n % 2 == 1 {
+        return Some(upper);
+    }
+    Some(0.5 * (lower.iter().copied().fold(f64::NEG_INFINITY, f64::max) + upper))
+}
+
+pub(crate) fn implied_black_volatility_relative(relative_price: f64, f: f64, k: f64, t: f64, q: bool) -> f64 {
+    implied_black_volatility(relative_price * f, f, k, t, q)
+}
+
+pub(crate) fn implied_black_volatility_approximation(price: f64, f: f64, k: f64, t: f64, q: bool) -> f64 {
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, 1).0
+}
+
+pub(crate) fn implied_black_volatility_from_log_moneyness(price: f64, f: f64, log_moneyness: f64, t: f64, q: bool) -> f64 {
+    implied_black_volatility(price, f, f * log_moneyness.exp(), t, q)
+}
+
+pub(crate) fn implied_black_volatility_refined(price: f64, f: f64, k: f64, t: f64, q: bool, extra_newton_steps: u32) -> f64 {
+    let sqrt_f = f.sqrt();
+    let (mut s, _) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, sqrt_f, k, q, 2, None);
+    if !(s.is_finite() && s > 0.0) {
+        return normalised_to_volatility(s, t.sqrt());
+    }
+    let x = (f / k).ln();
+    let scale = sqrt_f * k.sqrt();
+    let mut residual = black_from_s(f, k, s, q) - price;
+    for _ in 0..extra_newton_steps {
+        let trial = s - residual / (scale * normalised_vega(x, s));
+        let trial_residual = black_from_s(f, k, trial, q) - price;
+        // Only accept steps that reduce the repricing error; at the rounding floor, Newton steps just wander.
+        if trial_residual.is_nan() || trial_residual.abs() >= residual.abs() {
+            break;
+        }
+        (s, residual) = (trial, trial_residual);
+    }
+    s / t.sqrt()
+}
+
+pub(crate) fn black_vol_interval(price: f64, eps: f64, f: f64, k: f64, t: f64, q: bool) -> Option<(f64, f64)> {
+    let lower = implied_black_volatility(price - eps, f, k, t, q);
+    if lower == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC {
+        return None;
+    }
+    Some((lower, implied_black_volatility(price + eps, f, k, t, q)))
+}
+
+pub(crate) fn implied_black_volatility_with_diagnostics(price: f64, f: f64, k: f64, t: f64, q: bool, max_iterations: u8) -> (f64, Diagnostics) {
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, max_iterations)
+}
+
+pub(crate) fn implied_black_volatility_with_initial_guess(price: f64, f: f64, k: f64, t: f64, q: bool, initial_guess: f64) -> (f64, Diagnostics) {
+    let sqrt_t = t.sqrt();
+    let s0 = initial_guess * sqrt_t;
+    let s0 = if s0.is_finite() && s0 > 0.0 { Some(s0) } else { None };
+    let (s, diagnostics) = implied_normalised_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f.sqrt(), k, q, 2, s0);
+    (normalised_to_volatility(s, sqrt_t), diagnostics)
+}
+
+/// A reusable context for repeated implied black volatility solves.
+///
+/// It holds no state yet; it exists so that caches can be added later without changing the callers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlackSolver {}
+
+impl BlackSolver {
+    /// Creates a solver.
+    #[inline]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Calculates the implied black volatility, exactly as [`crate::implied_black_volatility`] does.
+    #[inline]
+    pub fn solve(&self, option_price: f64, forward: f64, strike: f64, expiry: f64, is_call: bool) -> f64 {
+        implied_black_volatility(option_price, forward, strike, expiry, is_call)
+    }
+}
+
+/// The reasons why [`crate::implied_black_volatility_checked`] cannot compute an implied black volatility.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImpliedVolError {
+    /// The option price is outside the attainable range `[intrinsic, max)`, where `max` is the forward for calls and
+    /// the strike for puts.
+    PriceOutOfRange {
+        /// The intrinsic value of the option.
+        intrinsic: f64,
+        /// The supremum of the attainable prices.
+        max: f64,
+    },
+    /// The price, forward or strike is NaN or infinite, or the expiry is NaN.
+    NonFiniteInput,
+    /// The expiry is negative.
+    NegativeExpiry,
+    /// The forward is zero or negative, which the black model does not admit.
+    NonPositiveForward,
+    /// The strike is zero or negative, which the black model does not admit.
+    NonPositiveStrike,
+    /// The expiry is zero and the price is above the intrinsic value, which no volatility attains.
+    TimeValueAtZeroExpiry,
+}
+
+impl std::fmt::Display for ImpliedVolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImpliedVolError::PriceOutOfRange { intrinsic, max } => write!(f, "option price is outside [{intrinsic}, {max})"),
+            ImpliedVolError::NonFiniteInput => f.write_str("input is not finite"),
+            ImpliedVolError::NegativeExpiry => f.write_str("expiry is negative"),
+            ImpliedVolError::NonPositiveForward => f.write_str("forward is not positive"),
+            ImpliedVolError::NonPositiveStrike => f.write_str("strike is not positive"),
+            ImpliedVolError::TimeValueAtZeroExpiry => f.write_str("option price is above the intrinsic value at zero expiry"),
+        }
+    }
+}
+
+impl std::error::Error for ImpliedVolError {}
+
+pub(crate) fn implied_black_volatility_checked(price: f64, f: f64, k: f64, t: f64, q: bool) -> Result<f64, ImpliedVolError> {
+    if !(price.is_finite() && f.is_finite() && k.is_finite()) || t.is_nan() {
+        return Err(ImpliedVolError::NonFiniteInput);
+    }
+    if t < 0.0 {
+        return Err(ImpliedVolError::NegativeExpiry);
+    }
+    if f <= 0.0 {
+        return Err(ImpliedVolError::NonPositiveForward);
+    }
+    if k <= 0.0 {
+        return Err(ImpliedVolError::NonPositiveStrike);
+    }
+    let sigma = implied_black_volatility(price, f, k, t, q);
+    let max = if q { f } else { k };
+    // At zero expiry the solver signals a price strictly between intrinsic and max as above the maximum too.
+    if sigma == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM && t == 0.0 && price < max {
+        return Err(ImpliedVolError::TimeValueAtZeroExpiry);
+    }
+    if sigma == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC || sigma == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM {
+        return Err(ImpliedVolError::PriceOutOfRange { intrinsic: intrinsic_value(f, k, q), max });
+    }
+    Ok(sigma)
+}
+
+pub(crate) fn implied_black_volatility_propagate(price: f64, f: f64, k: f64, t: f64, q: bool) -> f64 {
+    implied_black_volatility_checked(price, f, k, t, q).unwrap_or(f64::NAN)
+}
+
+pub(crate) fn implied_black_volatility_with_repricing_error(price: f64, f: f64, k: f64, t: f64, q: bool) -> (f64, f64) {
+    let sigma = implied_black_volatility(price, f, k, t, q);
+    (sigma, (price - black(f, k, sigma, t, q)).abs())
+}
+
+/// The result of inverting a price to an implied black volatility and repricing it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundTrip {
+    /// The implied black volatility.
+    pub vol: f64,
+    /// The black price at the implied volatility.
+    pub reprice: f64,
+    /// `|price - reprice| / price`, or `0.0` when both prices are zero.
+    pub rel_error: f64,
+}
+
+pub(crate) fn verify_black_roundtrip(price: f64, f: f64, k: f64, t: f64, q: bool) -> Option<RoundTrip> {
+    let vol = implied_black_volatility(price, f, k, t, q);
+    if !vol.is_finite() {
+        return None;
+    }
+    let reprice = black(f, k, vol, t, q);
+    let rel_error = if price == reprice { 0.0 } else { (price - reprice).abs() / price };
+    Some(RoundTrip { vol, reprice, rel_error })
+}
+
+/// Where an option price lies within the range of prices the black model can attain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuoteDiagnostics {
+    /// The intrinsic value of the option.
+    pub intrinsic: f64,
+    /// The supremum of the attainable prices, i.e. the forward for calls and the strike for puts.
+    pub supremum: f64,
+    /// The price minus the intrinsic value.
+    pub time_value: f64,
+    /// `(price - intrinsic) / (supremum - intrinsic)`, which lies in `[0, 1)` for attainable prices. It is meaningless
+    /// unless the forward and the strike are positive.
+    pub fraction_of_band: f64,
+    /// Whether [`crate::implied_black_volatility_checked`] succeeds for the price with a finite volatility.
+    pub solvable: bool,
+}
+
+pub(crate) fn quote_diagnostics(price: f64, f: f64, k: f64, t: f64, q: bool) -> QuoteDiagnostics {
+    let (intrinsic, time_value) = intrinsic_and_time_value(price, f, k, q);
+    let supremum = if q { f } else { k };
+    QuoteDiagnostics {
+        intrinsic,
+        supremum,
+        time_value,
+        fraction_of_band: time_value / (supremum - intrinsic),
+        solvable: implied_black_volatility_checked(price, f, k, t, q).is_ok_and(f64::is_finite),
+    }
 }
 
+pub(crate) fn black_vol_roundtrip_error(f: f64, k: f64, sigma: f64, t: f64, q: bool) -> f64 {
+    (sigma - implied_black_volatility(black(f, k, sigma, t, q), f, k, t, q)).abs() / sigma
+}
 
 #[cfg(test)]
 mod tests {
@@ -517,4 +1158,880 @@ mod tests {
             assert!((price - reprice).abs() <= 2.0 * f64::EPSILON);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn repricing_error_random_call_itm() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let price = 1.0 * (1.0 - r) + 1.0 * r * r2;
+            let f = 1.0;
+            let k = 1.0 * r;
+            let t = 1e5 * r3;
+            let q = true;
+            let (sigma, error) = implied_black_volatility_with_repricing_error(price, f, k, t, q);
+            assert_eq!(sigma, implied_black_volatility(price, f, k, t, q));
+            assert_eq!(error, (price - black(f, k, sigma, t, q)).abs());
+            assert!(error <= 2.0 * f64::EPSILON * f);
+        }
+    }
+
+    #[test]
+    fn bisection_fallback() {
+        let n = 1_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2): (f64, f64) = rng.gen();
+            let f = 1.0 * r;
+            let k = 1.0;
+            let q = true;
+            let price = f * r2;
+            let (s, _) = normalised_implied_volatility_by_bisection::<true>(price, f, k);
+            let reprice = black_from_s(f, k, s, q);
+            assert!((price - reprice).abs() <= 4.0 * f64::EPSILON);
+            assert!(normalised_implied_volatility_by_bisection::<true>(0.5 * price, f, k).0 <= s);
+            assert!(normalised_implied_volatility_by_bisection::<true>(price + 0.5 * (f - price), f, k).0 >= s);
+            // Both volatilities reprice to within a few ulps of f, which moves s by that much over the vega.
+            let vega = (f.sqrt() * k.sqrt()) * normalised_vega((f / k).ln(), s);
+            let tolerance = 4.0 * f64::EPSILON * (s + f / vega);
+            assert!((s - implied_normalised_black_volatility(price, f, k, q)).abs() <= tolerance);
+        }
+    }
+
+    #[test]
+    fn solver_falls_back_to_bisection() {
+        // Without iterations a NaN initial guess is returned as is, standing in for a diverged iteration.
+        for (f, k, q) in [(0.8, 1.0, true), (1.0, 0.8, true), (1.2, 1.0, false)] {
+            let price = black_from_s(f, k, 0.3, q);
+            let mut last = f64::NAN;
+            let (s, diagnostics) =
+                observed_implied_normalised_volatility(price, f, f.sqrt(), k, q, 0, Some(f64::NAN), f64::EPSILON, &mut |s| last = s);
+            assert_eq!(last, s);
+            assert!((s - 0.3).abs() <= 1e-14, "{f} {k} {q} {s}");
+            let Convergence::BisectionFallback { iterations } = diagnostics.convergence else {
+                panic!("{diagnostics:?}");
+            };
+            assert!(iterations > 50, "{iterations}");
+            if intrinsic_value(f, k, q) == 0.0 {
+                let bisection = if q {
+                    normalised_implied_volatility_by_bisection::<true>(price, f, k)
+                } else {
+                    normalised_implied_volatility_by_bisection::<false>(price, f, k)
+                };
+                assert_eq!((s, iterations), bisection);
+            }
+        }
+    }
+
+    #[test]
+    fn normalised_volatility() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let f = 1.0;
+            let k = 2.0 * r;
+            let t = 10.0 * r3;
+            let q = i % 2 == 0;
+            let sigma = 0.01 + r2;
+            let s = sigma * t.sqrt();
+            assert_eq!(black_from_s(f, k, s, q), black(f, k, sigma, t, q));
+            let price = black(f, k, sigma, t, q);
+            assert_eq!(
+                implied_normalised_black_volatility(price, f, k, q) / t.sqrt(),
+                implied_black_volatility(price, f, k, t, q)
+            );
+        }
+    }
+
+    #[test]
+    fn time_inf() {
+        let t = f64::INFINITY;
+        for q in [true, false] {
+            for (f, k) in [(100.0, 100.0_f64), (100.0, 90.0), (90.0, 100.0)] {
+                let intrinsic = if q { f - k } else { k - f }.max(0.0);
+                let max_price = if q { f } else { k };
+                for price in [intrinsic, intrinsic + 0.01, 0.5 * (intrinsic + max_price)] {
+                    assert_eq!(implied_black_volatility(price, f, k, t, q), 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalisation_without_overflow() {
+        // f k overflows or underflows here, while sqrt(f) sqrt(k) does not.
+        for (f, k) in [(1e200, 1e200), (1e200, 1.1e200), (1e-200, 1e-200), (1e-200, 0.9e-200)] {
+            for q in [true, false] {
+                let price = black(f, k, 0.2, 1.0, q);
+                let sigma = implied_black_volatility(price, f, k, 1.0, q);
+                assert!((sigma - 0.2).abs() <= 1e-14, "{f} {k} {q} {sigma}");
+            }
+        }
+    }
+
+    #[test]
+    fn no_panic_on_finite_in_domain_inputs() {
+        let n = 1_000_000;
+        let seed: [u8; 32] = [9; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2, r3, r4): (f64, f64, f64, f64) = rng.gen();
+            let f = 10f64.powf(-300.0 + 600.0 * r);
+            let k = f * 10f64.powf(-30.0 + 60.0 * r2);
+            let t = if i % 7 == 0 { 0.0 } else { 10f64.powf(-30.0 + 35.0 * r3) };
+            let q = i % 2 == 0;
+            let intrinsic = if q { f - k } else { k - f }.max(0.0);
+            let max_price = if q { f } else { k };
+            let price = match i % 5 {
+                0 => intrinsic,
+                1 => 0.0,
+                _ => intrinsic + (max_price - intrinsic) * r4,
+            };
+            let sigma = implied_black_volatility(price, f, k, t, q);
+            assert!(t == 0.0 || !sigma.is_nan());
+        }
+    }
+
+    #[test]
+    fn roundtrip_verification() {
+        let (f, k, t) = (1.0, 0.009311946307616337, 8.13389953271989);
+        let price = black(f, k, 0.1637453664797791, t, false);
+        let round_trip = verify_black_roundtrip(price, f, k, t, false).unwrap();
+        assert_eq!(round_trip.reprice, black(f, k, round_trip.vol, t, false));
+        assert!(round_trip.rel_error <= 1e-12);
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2): (f64, f64) = rng.gen();
+            let q = i % 2 == 0;
+            let price = black(100.0, 50.0 + 100.0 * r, 0.05 + r2, 1.0, q);
+            assert!(verify_black_roundtrip(price, 100.0, 50.0 + 100.0 * r, 1.0, q).unwrap().rel_error <= 1e-12);
+        }
+        assert_eq!(verify_black_roundtrip(0.0, 100.0, 100.0, 1.0, true).unwrap().rel_error, 0.0);
+        assert!(verify_black_roundtrip(-1.0, 100.0, 100.0, 1.0, true).is_none());
+        assert!(verify_black_roundtrip(100.0, 100.0, 100.0, 1.0, true).is_none());
+    }
+
+    #[test]
+    fn warm_start() {
+        let (f, t) = (100.0, 1.0);
+        for (k, sigma, q) in [(80.0, 0.3, false), (100.0, 0.2, true), (120.0, 0.25, true), (105.0, 0.4, false)] {
+            let price = black(f, k, sigma, t, q);
+            let (cold, cold_diagnostics) = implied_black_volatility_with_diagnostics(price, f, k, t, q, 2);
+            let (warm, warm_diagnostics) = implied_black_volatility_with_initial_guess(price, f, k, t, q, cold);
+            assert!((warm - cold).abs() <= 4.0 * f64::EPSILON * cold);
+            assert_eq!(warm_diagnostics.convergence, Convergence::Converged);
+            assert!(warm_diagnostics.iterations < cold_diagnostics.iterations);
+            let (warm, warm_diagnostics) = implied_black_volatility_with_initial_guess(price, f, k, t, q, 1.01 * sigma);
+            assert!((warm - cold).abs() <= 4.0 * f64::EPSILON * cold);
+            assert_eq!(warm_diagnostics.convergence, Convergence::Converged);
+        }
+        for initial_guess in [f64::NAN, 0.0, -1.0, f64::INFINITY] {
+            let price = black(f, 90.0, 0.3, t, true);
+            assert_eq!(implied_black_volatility_with_initial_guess(price, f, 90.0, t, true, initial_guess), implied_black_volatility_with_diagnostics(price, f, 90.0, t, true, 2));
+        }
+    }
+
+    #[test]
+    fn inv_normalised_vega_is_reciprocal() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..10_000 {
+            let (r, r2): (f64, f64) = rng.gen();
+            let x = -2.0 + 4.0 * r;
+            let s = 0.1 + 5.0 * r2;
+            assert!((inv_normalised_vega(x, s) * normalised_vega(x, s) - 1.0).abs() <= 4.0 * f64::EPSILON);
+        }
+        assert_eq!(inv_normalised_vega(0.0, 0.0), SQRT_TWO_PI);
+        assert_eq!(inv_normalised_vega(1.0, 0.0), f64::INFINITY);
+        assert_eq!(inv_normalised_vega(100.0, 1e-3), f64::INFINITY);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, not(feature = "no-runtime-checks")))]
+    #[should_panic]
+    fn asymptotic_expansion_rejects_inputs_outside_its_domain() {
+        asymptotic_expansion_of_normalised_black_call_over_vega(-1.0, 0.5);
+    }
+
+    #[test]
+    fn scaled_normalised_black_and_ln_vega() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..10_000 {
+            let (r, r2): (f64, f64) = rng.gen();
+            let x = -5.0 * r;
+            let s = 0.05 + 5.0 * r2;
+            let (bx, ln_vega) = normalised_black_call_over_vega_and_ln_vega(x, s);
+            let b = normalised_black_call(x, s);
+            assert!(((bx.ln() + ln_vega).exp() - b).abs() <= 1e-13 * b);
+        }
+        // Deep out of the money the price underflows, but its logarithm does not.
+        let (x, s) = (-60.0, 0.5);
+        assert_eq!(normalised_black_call(x, s), 0.0);
+        let (bx, ln_vega) = normalised_black_call_over_vega_and_ln_vega(x, s);
+        let ln_b = bx.ln() + ln_vega;
+        assert!(ln_b.is_finite() && ln_b < f64::MIN_POSITIVE.ln());
+    }
+
+    #[test]
+    fn vol_roundtrip_error_bound() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let k = 0.5 + r;
+            let sigma = 0.1 + 0.9 * r2;
+            let t = 0.25 + 9.75 * r3;
+            assert!(black_vol_roundtrip_error(1.0, k, sigma, t, k > 1.0) <= 1e-14);
+        }
+    }
+
+    #[test]
+    fn normalised_black_and_vega_match_separate_evaluations() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..100_000 {
+            let (r, r2): (f64, f64) = rng.gen();
+            let x = 100.0 * (2.0 * r - 1.0) * r * r;
+            let s = 20.0 * r2 * r2 * r2;
+            let q = i % 2 == 0;
+            let (price, vega) = normalised_black_and_vega(x, s, q);
+            assert_eq!(price.to_bits(), normalised_black(x, s, q).to_bits());
+            assert_eq!(vega.to_bits(), normalised_vega(x, s).to_bits());
+        }
+        for (x, s) in [(0.0, 0.0), (-0.0, 1.0), (0.0, 1.0), (-40.0, 1.0), (40.0, 1.0), (-0.5, 1e-9)] {
+            for q in [true, false] {
+                assert_eq!(normalised_black_and_vega(x, s, q), (normalised_black(x, s, q), normalised_vega(x, s)));
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_expiry_matches_expiry() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (k, sigma, t) = (0.5 + r, 0.01 + r2, 1e-3 + 30.0 * r3);
+            let q = i % 2 == 0;
+            let price = black(1.0, k, sigma, t, q);
+            assert_eq!(black_sqrt_expiry(1.0, k, sigma, t.sqrt(), q).to_bits(), price.to_bits());
+            assert_eq!(implied_black_volatility_sqrt_expiry(price, 1.0, k, t.sqrt(), q).to_bits(), implied_black_volatility(price, 1.0, k, t, q).to_bits());
+        }
+    }
+
+    #[test]
+    fn array_matches_scalar_calls() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..1_000 {
+            let mut prices = [0.0; 8];
+            let mut fs = [0.0; 8];
+            let mut ks = [0.0; 8];
+            let mut ts = [0.0; 8];
+            let mut qs = [false; 8];
+            for i in 0..8 {
+                let (r, r2, r3, r4): (f64, f64, f64, f64) = rng.gen();
+                (fs[i], ks[i], ts[i], qs[i]) = (0.5 + r, 0.5 + r2, 0.1 + 10.0 * r3, i % 2 == 0);
+                prices[i] = black(fs[i], ks[i], 0.01 + r4, ts[i], qs[i]);
+            }
+            let vols = implied_black_volatility_array(prices, fs, ks, ts, qs);
+            for i in 0..8 {
+                assert_eq!(vols[i].to_bits(), implied_black_volatility(prices[i], fs[i], ks[i], ts[i], qs[i]).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn region_boundaries() {
+        // The asymptotic expansion applies for h = x / s < -10 and h + s / 2 < -10 - 2 * EPSILON^(1/16).
+        let s = 1.0;
+        let boundary = s * (ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD - 0.5 * s + SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD);
+        assert_eq!(region(boundary - 1e-12, s), Region::Asymptotic);
+        assert_eq!(region(boundary + 1e-12, s), Region::Cody);
+        assert_eq!(region(-(boundary - 1e-12), s), Region::Asymptotic);
+        let s = 2.0 * SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD;
+        assert_eq!(region(-0.1 * s, s * (1.0 - 1e-12)), Region::SmallT);
+        assert_eq!(region(-0.1 * s, s * (1.0 + 1e-12)), Region::Cody);
+        assert_eq!(region(-1.0, DENORMALISATION_CUTOFF), Region::Intrinsic);
+        assert_eq!(region(-1.0, 1e-300), Region::Asymptotic);
+        assert_eq!(region(0.0, 0.0), Region::Intrinsic);
+        assert_eq!(region(0.0, 1.0), Region::Cody);
+    }
+
+    #[test]
+    fn inversion_recovers_black_volatility() {
+        let n = 1_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3, q): (f64, f64, f64, bool) = rng.gen();
+            let f = 100.0;
+            let k = 50.0 + 150.0 * r;
+            let sigma = 0.05 + 0.95 * r2;
+            let t = 0.1 + 4.9 * r3;
+            let price = black(f, k, sigma, t, q);
+            let price_fn = |sigma| black(f, k, sigma, t, q);
+            let s = implied_volatility_by_inversion(price, price_fn, 0.0, 10.0).unwrap();
+            // In the money, the time value and hence the volatility is resolved only to the precision of the price.
+            if q == (k > f) {
+                assert!((s - sigma).abs() <= 1e-12 * sigma, "{s} vs {sigma}");
+            } else {
+                assert!((price_fn(s) - price).abs() <= 4.0 * f64::EPSILON * price);
+            }
+        }
+        let price = black(100.0, 100.0, 0.2, 1.0, true);
+        let price_fn = |sigma| black(100.0, 100.0, sigma, 1.0, true);
+        assert_eq!(implied_volatility_by_inversion(price, price_fn, 0.3, 1.0), None);
+        assert_eq!(implied_volatility_by_inversion(price, price_fn, 1.0, 0.1), None);
+        assert_eq!(implied_volatility_by_inversion(f64::NAN, price_fn, 0.0, 1.0), None);
+        assert_eq!(implied_volatility_by_inversion(0.0, price_fn, 0.0, 1.0), Some(0.0));
+        assert!((implied_volatility_by_inversion(price, price_fn, 0.0, 1e6).unwrap() - 0.2).abs() <= 1e-12);
+        assert_eq!(implied_volatility_by_inversion(price, price_fn, 0.0, f64::INFINITY), None);
+        assert_eq!(implied_volatility_by_inversion(price, price_fn, f64::NEG_INFINITY, 1.0), None);
+    }
+
+    #[test]
+    fn band_breakpoints_invert_to_their_volatilities() {
+        for x in [-1e-3, -0.1, -1.0, -5.0, -30.0, -200.0] {
+            let (b_l, b_c, b_u) = normalised_band(x);
+            assert_eq!(normalised_band(-x), (b_l, b_c, b_u));
+            assert!(0.0 < b_l && b_l < b_c && b_c < b_u && b_u < (0.5 * x).exp());
+            let s_c = SQRT_2 * x.abs().sqrt();
+            let v_c = normalised_vega(x, s_c);
+            let b_max = (0.5 * x).exp();
+            for (beta, s) in [(b_l, s_c - b_c / v_c), (b_c, s_c), (b_u, s_c + (b_max - b_c) / v_c)] {
+                let implied = unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations::<true>(beta, x, 2, None, f64::EPSILON, &mut |_| {}).0;
+                assert!((implied - s).abs() <= 1e-13 * s, "{x}: {implied} vs {s}");
+            }
+        }
+        let (b_l, b_c, b_u) = normalised_band(0.0);
+        assert_eq!((b_l, b_c), (0.0, 0.0));
+        assert_eq!(b_u, normalised_black_call(0.0, SQRT_TWO_PI));
+    }
+
+    #[test]
+    fn asymptotic_expansion_boundary() {
+        let (f, k, sigma, t) = (1.0, 0.009311946307616337, 0.1637453664797791, 8.13389953271989);
+        let price = black(f, k, sigma, t, true);
+        assert!(price >= f - k && price < f);
+        let put = black(f, k, sigma, t, false);
+        assert!((price - put - (f - k)).abs() <= 2.0 * f64::EPSILON);
+    }
+
+    #[test]
+    fn smile() {
+        let n = 1_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        let f = 1.0;
+        let t = 2.0;
+        let mut strikes = Vec::with_capacity(n);
+        let mut prices = Vec::with_capacity(n);
+        let mut qs = Vec::with_capacity(n);
+        for i in 0..n {
+            let (r, r2): (f64, f64) = rng.gen();
+            let k = 2.0 * r;
+            let q = i % 2 == 0;
+            strikes.push(k);
+            prices.push(black(f, k, r2, t, q));
+            qs.push(q);
+        }
+        let mut out = vec![0.0; n];
+        implied_black_volatility_smile(f, t, &strikes, &prices, &qs, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], implied_black_volatility(prices[i], f, strikes[i], t, qs[i]));
+        }
+    }
+
+    #[test]
+    fn slice_tol_stays_within_tolerance() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        let mut prices = Vec::with_capacity(n);
+        let mut fs = Vec::with_capacity(n);
+        let mut ks = Vec::with_capacity(n);
+        let mut ts = Vec::with_capacity(n);
+        let mut qs = Vec::with_capacity(n);
+        for i in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (f, k, t, q) = (1.0, 0.1 + 3.0 * r, 0.01 + 5.0 * r3, i % 2 == 0);
+            prices.push(black(f, k, 0.05 + r2, t, q));
+            fs.push(f);
+            ks.push(k);
+            ts.push(t);
+            qs.push(q);
+        }
+        let mut out = vec![0.0; n];
+        implied_black_volatility_slice_tol(&prices, &fs, &ks, &ts, &qs, 0.0, &mut out);
+        for i in 0..n {
+            assert_eq!(out[i], implied_black_volatility(prices[i], fs[i], ks[i], ts[i], qs[i]));
+        }
+        for rel_tol in [1e-12, 1e-8, 1e-6] {
+            implied_black_volatility_slice_tol(&prices, &fs, &ks, &ts, &qs, rel_tol, &mut out);
+            for i in 0..n {
+                let exact = implied_black_volatility(prices[i], fs[i], ks[i], ts[i], qs[i]);
+                assert!((out[i] - exact).abs() <= rel_tol * exact, "{rel_tol} {} {exact}", out[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn signed_zero_and_adjacent_moneyness() {
+        for k in [1.0, 100.0, 2.0 - 2.0 * f64::EPSILON, 1e-300] {
+            let adjacent = [f64::from_bits(k.to_bits() - 1), f64::from_bits(k.to_bits() + 1)];
+            for f in [k, k + 0.0 * f64::EPSILON, adjacent[0], adjacent[1]] {
+                // The in-the-money side is chosen from the sign of f - k in pricing and of ln(f / k) in the
+                // inversion, which agree even for adjacent floating-point numbers.
+                assert_eq!((f - k).is_sign_negative(), (f / k).ln().is_sign_negative());
+                for q in [true, false] {
+                    for price in [0.0, -0.0] {
+                        if intrinsic_value(f, k, q) == 0.0 {
+                            assert_eq!(implied_black_volatility(price, f, k, 1.0, q), 0.0);
+                        }
+                    }
+                    let price = black(f, k, 0.2, 1.0, q);
+                    let sigma = implied_black_volatility(price, f, k, 1.0, q);
+                    assert!((sigma - 0.2).abs() <= 4.0 * f64::EPSILON * 0.2, "{f} {k} {q} {sigma}");
+                }
+                if f == k {
+                    assert_eq!(black(f, k, 0.2, 1.0, true), black(f, k, 0.2, 1.0, false));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn vega_at_the_implied_volatility() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (f, k, t, q) = (100.0, 50.0 + 100.0 * r, 0.01 + 5.0 * r3, i % 2 == 0);
+            let price = black(f, k, 0.05 + r2, t, q);
+            let (sigma, vega) = implied_black_volatility_and_vega(price, f, k, t, q).unwrap();
+            assert_eq!(sigma, implied_black_volatility(price, f, k, t, q));
+            let expected = crate::greeks::greeks(f, k, sigma, t, q).vega;
+            assert!((vega - expected).abs() <= 1e-13 * expected, "{f} {k} {t} {sigma} {vega} {expected}");
+        }
+        assert_eq!(implied_black_volatility_and_vega(200.0, 100.0, 90.0, 1.0, true), None);
+    }
+
+    #[test]
+    fn large_forwards() {
+        // The prices are scaled by sqrt(f) sqrt(k) rather than f or k, so even f k beyond f64::MAX does not overflow.
+        // References computed with 50-digit arithmetic, for calls and puts at σ = 0.3 and T = 1.
+        for (f, k, call, put) in [
+            (1e9, 1e9, 119_235_384.740_485_03, 119_235_384.740_485_03),
+            (1e9, 1.2e9, 54_405_634.678_143_06, 254_405_634.678_143_05),
+            (1e9, 0.8e9, 235_343_901.031_737_57, 35_343_901.031_737_56),
+            (1e300, 1e300, 1.192_353_847_404_850_4e299, 1.192_353_847_404_850_4e299),
+            (1e308, 1.5e308, 1.485_893_829_820_29e306, 5.148_589_382_982_029e307),
+        ] {
+            for (q, expected) in [(true, call), (false, put)] {
+                let price = black(f, k, 0.3, 1.0, q);
+                assert!((price - expected).abs() <= 4.0 * f64::EPSILON * expected);
+                let sigma = implied_black_volatility(price, f, k, 1.0, q);
+                assert!((sigma - 0.3).abs() <= 2.0 * f64::EPSILON * 0.3);
+            }
+        }
+    }
+
+    #[test]
+    fn initial_guess_is_close() {
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for i in 0..10_000 {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (f, k, t, q) = (100.0, 50.0 + 100.0 * r, 0.01 + 5.0 * r3, i % 2 == 0);
+            let price = black(f, k, 0.05 + r2, t, q);
+            if price < f64::MIN_POSITIVE {
+                continue;
+            }
+            let sigma = implied_black_volatility(price, f, k, t, q);
+            let guess = black_vol_initial_guess(price, f, k, t, q).unwrap();
+            assert!((guess - sigma).abs() <= 0.1 * sigma, "{k} {t} {q} {sigma} {guess}");
+        }
+        assert_eq!(black_vol_initial_guess(200.0, 100.0, 90.0, 1.0, true), None);
+    }
+
+    #[test]
+    fn diagnostics_report_non_convergence_under_low_cap() {
+        let (price, f, k, t) = (1e-100, 1.0, 1.5, 1.0);
+        let (_, diagnostics) = implied_black_volatility_with_diagnostics(price, f, k, t, true, 1);
+        assert_eq!(diagnostics, Diagnostics { iterations: 1, convergence: Convergence::MaxIterationsReached });
+        let (sigma, diagnostics) = implied_black_volatility_with_diagnostics(price, f, k, t, true, 2);
+        assert_eq!(diagnostics.convergence, Convergence::Converged);
+        assert_eq!(sigma, implied_black_volatility(price, f, k, t, true));
+    }
+
+    #[test]
+    fn diagnostics_default_cap_converges() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let price = 1.0 * r * r2;
+            let f = 1.0 * r;
+            let k = 1.0;
+            let t = 1e5 * r3;
+            let (_, diagnostics) = implied_black_volatility_with_diagnostics(price, f, k, t, true, 2);
+            assert_eq!(diagnostics.convergence, Convergence::Converged);
+        }
+    }
+
+    #[test]
+    fn vol_interval() {
+        let (f, t, sigma, eps) = (100.0, 1.0, 0.2, 1e-6);
+        let width = |k: f64| {
+            let (lower, upper) = black_vol_interval(black(f, k, sigma, t, true), eps, f, k, t, true).unwrap();
+            assert!(lower <= sigma && sigma <= upper);
+            upper - lower
+        };
+        let atm = width(100.0);
+        let otm = width(200.0);
+        assert!(atm < 1e-6);
+        assert!(otm > 100.0 * atm);
+        assert_eq!(black_vol_interval(10.0, 1.0, f, 90.0, t, true), None);
+        assert_eq!(black_vol_interval(99.5, 1.0, f, 90.0, t, true).map(|(_, upper)| upper), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn refined_reconstruction_random_call_itm() {
+        let n = 100_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        let mut base_error = 0.0;
+        let mut refined_error = 0.0;
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let price = 1.0 * (1.0 - r) + 1.0 * r * r2;
+            let f = 1.0;
+            let k = 1.0 * r;
+            let t = 1e5 * r3;
+            let q = true;
+            let sigma = implied_black_volatility(price, f, k, t, q);
+            assert_eq!(implied_black_volatility_refined(price, f, k, t, q, 0), sigma);
+            base_error += (price - black(f, k, sigma, t, q)).abs();
+            let sigma = implied_black_volatility_refined(price, f, k, t, q, 3);
+            let error = (price - black(f, k, sigma, t, q)).abs();
+            assert!(error <= 2.0 * f64::EPSILON);
+            refined_error += error;
+        }
+        // The repricing error drops by about a third (the ratio is 0.666 for this seed).
+        assert!(refined_error < 0.7 * base_error);
+    }
+
+    #[test]
+    fn from_log_moneyness() {
+        let f = 100.0;
+        let t = 1.0;
+        for log_moneyness in [-1.0, -0.1, 0.0, 0.1, 1.0_f64] {
+            let k = f * log_moneyness.exp();
+            for q in [true, false] {
+                let price = black(f, k, 0.2, t, q);
+                assert_eq!(
+                    implied_black_volatility_from_log_moneyness(price, f, log_moneyness, t, q),
+                    implied_black_volatility(price, f, k, t, q)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn approximation_grid() {
+        let f = 1.0;
+        for k in [0.01, 0.1, 0.5, 0.9, 1.0, 1.1, 2.0, 10.0, 100.0] {
+            for sigma in [0.01, 0.05, 0.1, 0.2, 0.5, 1.0, 2.0] {
+                for t in [0.01, 0.1, 1.0, 5.0] {
+                    for q in [true, false] {
+                        let price = black(f, k, sigma, t, q);
+                        let exact = implied_black_volatility(price, f, k, t, q);
+                        let approximation = implied_black_volatility_approximation(price, f, k, t, q);
+                        if exact == 0.0 {
+                            assert_eq!(approximation, 0.0);
+                        } else {
+                            assert!(((approximation - exact) / exact).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn intrinsic_and_time_value_split() {
+        assert_eq!(intrinsic_and_time_value(12.0, 100.0, 90.0, true), (10.0, 2.0));
+        assert_eq!(intrinsic_and_time_value(2.0, 100.0, 90.0, false), (0.0, 2.0));
+        assert_eq!(intrinsic_and_time_value(3.0, 100.0, 110.0, true), (0.0, 3.0));
+        assert_eq!(intrinsic_and_time_value(13.0, 100.0, 110.0, false), (10.0, 3.0));
+        assert_eq!(intrinsic_and_time_value(4.0, 100.0, 100.0, true), (0.0, 4.0));
+        assert_eq!(intrinsic_and_time_value(4.0, 100.0, 100.0, false), (0.0, 4.0));
+        assert_eq!(intrinsic_and_time_value(9.0, 100.0, 90.0, true), (10.0, -1.0));
+    }
+
+    #[test]
+    fn solver_matches_free_function() {
+        let solver = BlackSolver::new();
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let price = 1.0 * r * r2;
+            let f = 1.0 * r;
+            let k = 1.0;
+            let t = 1e5 * r3;
+            for q in [true, false] {
+                assert_eq!(solver.solve(price, f, k, t, q).to_bits(), implied_black_volatility(price, f, k, t, q).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn relative_price() {
+        let f = 100.0;
+        let t = 1.0;
+        for k in [50.0, 90.0, 100.0, 110.0, 200.0] {
+            for q in [true, false] {
+                let price = black(f, k, 0.3, t, q);
+                assert_eq!(implied_black_volatility_relative(price / f, f, k, t, q), implied_black_volatility(price, f, k, t, q));
+            }
+        }
+        assert_eq!(implied_black_volatility_relative(1.0, f, 90.0, t, true), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+        assert_eq!(implied_black_volatility_relative(-0.1, f, 110.0, t, true), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+    }
+
+    #[test]
+    fn atm_normalised_volatility() {
+        for i in 0..3000 {
+            let beta = 0.99_f64.powi(i) * (1.0 - 1e-9);
+            let s = implied_normalised_volatility_atm(beta);
+            for q in [true, false] {
+                let general = implied_normalised_black_volatility(beta, 1.0, 1.0, q);
+                assert!((s - general).abs() <= 4.0 * f64::EPSILON * general);
+            }
+        }
+        assert_eq!(implied_normalised_volatility_atm(0.0), 0.0);
+        assert_eq!(implied_normalised_volatility_atm(1.0), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+        assert_eq!(implied_normalised_volatility_atm(-1e-3), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+    }
+
+    #[test]
+    fn price_within_ulps_of_intrinsic() {
+        let (f, k, t) = (1e4, 1.0, 1.0);
+        let intrinsic = f - k;
+        let ulp = |x: f64| f64::from_bits(x.to_bits() + 1) - x;
+        assert_eq!(implied_black_volatility(intrinsic, f, k, t, true), 0.0);
+        let sigma = implied_black_volatility(intrinsic + ulp(intrinsic), f, k, t, true);
+        assert!(sigma.is_finite() && sigma > 0.0);
+        assert_eq!(black(f, k, sigma, t, true), intrinsic + ulp(intrinsic));
+        assert_eq!(implied_black_volatility(intrinsic - ulp(intrinsic), f, k, t, true), 0.0);
+        assert_eq!(implied_black_volatility(intrinsic - 4.0 * ulp(intrinsic), f, k, t, true), 0.0);
+        assert_eq!(
+            implied_black_volatility(intrinsic * (1.0 - 1e-12), f, k, t, true),
+            VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC
+        );
+        // Below a power of two the ulps halve, where a relative tolerance of 4 eps would admit 8 of them.
+        let (f, k) = (1025.0, 1.0);
+        let intrinsic: f64 = f - k;
+        let ulps_below = |n: u64| f64::from_bits(intrinsic.to_bits() - n);
+        assert_eq!(implied_black_volatility(ulps_below(4), f, k, t, true), 0.0);
+        assert_eq!(implied_black_volatility(ulps_below(5), f, k, t, true), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+    }
+
+    #[test]
+    fn price_curve() {
+        let sigmas: Vec<f64> = (0..100).map(|i| 0.01 * i as f64).collect();
+        let mut out = vec![0.0; sigmas.len()];
+        for (f, k) in [(100.0, 100.0), (100.0, 80.0), (100.0, 130.0), (1.0, 1e-3)] {
+            for q in [true, false] {
+                black_price_curve(f, k, 2.0, q, &sigmas, &mut out);
+                for (&sigma, &price) in sigmas.iter().zip(&out) {
+                    assert_eq!(price.to_bits(), black(f, k, sigma, 2.0, q).to_bits());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_price_out_of_range() {
+        let (f, k, t) = (100.0, 90.0, 1.0);
+        for price in [5.0, 100.0, 150.0] {
+            assert_eq!(implied_black_volatility_checked(price, f, k, t, true), Err(ImpliedVolError::PriceOutOfRange { intrinsic: 10.0, max: f }));
+        }
+        for price in [-1.0, 90.0] {
+            assert_eq!(implied_black_volatility_checked(price, f, k, t, false), Err(ImpliedVolError::PriceOutOfRange { intrinsic: 0.0, max: k }));
+        }
+        assert_eq!(implied_black_volatility_checked(20.0, f, k, t, true), Ok(implied_black_volatility(20.0, f, k, t, true)));
+        assert_eq!(implied_black_volatility_checked(f64::NAN, f, k, t, true), Err(ImpliedVolError::NonFiniteInput));
+        assert_eq!(implied_black_volatility_checked(20.0, f, k, f64::NAN, true), Err(ImpliedVolError::NonFiniteInput));
+    }
+
+    #[test]
+    fn checked_rejects_invalid_inputs() {
+        let (f, k, t) = (100.0, 90.0, 1.0);
+        for q in [true, false] {
+            for (f, k, t, error) in [
+                (f, k, -1.0, ImpliedVolError::NegativeExpiry),
+                (-f, k, t, ImpliedVolError::NonPositiveForward),
+                (0.0, k, t, ImpliedVolError::NonPositiveForward),
+                (f, -k, t, ImpliedVolError::NonPositiveStrike),
+                (f, 0.0, t, ImpliedVolError::NonPositiveStrike),
+            ] {
+                let result = implied_black_volatility_checked(10.0, f, k, t, q);
+                assert!(result.is_err());
+                assert_eq!(result, Err(error));
+                assert!(implied_black_volatility_propagate(10.0, f, k, t, q).is_nan());
+                assert_eq!(implied_black_volatility_median(&[10.0, 12.0], f, k, t, q), None);
+            }
+        }
+        assert_eq!(ImpliedVolError::NegativeExpiry.to_string(), "expiry is negative");
+    }
+
+    #[test]
+    fn trace_ends_at_the_result() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3, q): (f64, f64, f64, bool) = rng.gen();
+            let (f, k, t) = (1.0, 0.01 + 10.0 * r, 0.01 + 10.0 * r3);
+            let price = black(f, k, 0.01 + 2.0 * r2, t, q);
+            let sigma = implied_black_volatility(price, f, k, t, q);
+            let Some((traced, trace)) = implied_black_volatility_traced(price, f, k, t, q) else {
+                assert!(!sigma.is_finite());
+                continue;
+            };
+            assert_eq!(traced, sigma);
+            if let Some(&last) = trace.last() {
+                assert_eq!(last, sigma);
+            } else {
+                assert_eq!(sigma, 0.0);
+            }
+            assert!(trace.len() <= 4);
+        }
+        assert_eq!(implied_black_volatility_traced(10.0, 100.0, 90.0, 1.0, true), Some((0.0, vec![])));
+        assert_eq!(implied_black_volatility_traced(100.0, 100.0, 90.0, 1.0, true), None);
+    }
+
+    #[test]
+    fn normalised_intrinsic_avoids_cancellation() {
+        for i in 0..=400 {
+            let x = 10_f64.powf(-12.0 + 0.035 * i as f64);
+            let exact = 2.0 * (0.5 * x).sinh();
+            // The series keeps full relative accuracy below |x| = 0.109, above which the difference of exponentials is
+            // accurate to a few ulps of exp(x / 2).
+            let tolerance = if x < 0.1 { 2.0 * f64::EPSILON * exact } else { 4.0 * f64::EPSILON * exact.max(1.0) };
+            assert!((normalised_intrinsic(x, true) - exact).abs() <= tolerance, "{x}");
+            assert!((normalised_intrinsic(-x, false) - exact).abs() <= tolerance, "{x}");
+            assert_eq!(normalised_intrinsic(-x, true), 0.0);
+            assert_eq!(normalised_intrinsic(x, false), 0.0);
+        }
+        // The naive difference loses about log10(1 / x) digits.
+        let x = 1e-10_f64;
+        let naive = (0.5 * x).exp() - (-0.5 * x).exp();
+        assert!((naive - x).abs() > 1e-8 * x);
+        assert!((normalised_intrinsic(x, true) - x).abs() <= f64::EPSILON * x);
+    }
+
+    #[test]
+    fn straddle() {
+        let n = 10_000;
+        let seed: [u8; 32] = [13; 32];
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(seed);
+        for _ in 0..n {
+            let (r, r2, r3): (f64, f64, f64) = rng.gen();
+            let (f, k, t) = (100.0, 70.0 + 60.0 * r, 0.1 + 5.0 * r3);
+            let sigma = 0.1 + 0.5 * r2;
+            let price = black(f, k, sigma, t, true) + black(f, k, sigma, t, false);
+            let implied = implied_black_volatility_from_straddle(price, f, k, t).unwrap();
+            // The time value is only known to the precision of the straddle price, which holds the intrinsic value.
+            let straddle_vega = 2.0 * (f * k * t).sqrt() * normalised_vega((f / k).ln(), sigma * t.sqrt());
+            assert!((implied - sigma).abs() <= 1e-14 * sigma + 4.0 * f64::EPSILON * price / straddle_vega);
+        }
+        for t in [0.01, 1.0, 30.0] {
+            let call = black(100.0, 100.0, 0.2, t, true);
+            assert_eq!(implied_black_volatility_from_straddle(2.0 * call, 100.0, 100.0, t), Some(implied_black_volatility(call, 100.0, 100.0, t, true)));
+        }
+        assert_eq!(implied_black_volatility_from_straddle(9.0, 100.0, 90.0, 1.0), None);
+        assert_eq!(implied_black_volatility_from_straddle(200.0, 100.0, 90.0, 1.0), None);
+    }
+
+    #[test]
+    fn median_ignores_out_of_range_prices() {
+        let (f, k, t) = (100.0, 110.0, 0.5);
+        let price = |sigma| black(f, k, sigma, t, true);
+        let vol = |price| implied_black_volatility(price, f, k, t, true);
+        let prices = [price(0.21), price(0.19), 150.0, price(0.2), price(0.25)];
+        assert_eq!(implied_black_volatility_median(&prices, f, k, t, true), Some(0.5 * (vol(prices[0]) + vol(prices[3]))));
+        assert_eq!(implied_black_volatility_median(&prices[1..], f, k, t, true), Some(vol(prices[3])));
+        assert_eq!(implied_black_volatility_median(&[f64::NAN, 150.0], f, k, t, true), None);
+        assert_eq!(implied_black_volatility_median(&[], f, k, t, true), None);
+    }
+
+    #[test]
+    fn zero_expiry() {
+        let (f, k) = (100.0, 90.0);
+        assert_eq!(implied_black_volatility(10.0, f, k, 0.0, true), 0.0);
+        assert_eq!(implied_black_volatility(0.0, f, k, 0.0, false), 0.0);
+        assert_eq!(implied_black_volatility(0.0, f, f, 0.0, true), 0.0);
+        assert_eq!(implied_black_volatility(10.5, f, k, 0.0, true), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+        assert_eq!(implied_black_volatility(0.5, f, k, 0.0, false), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+        assert_eq!(implied_black_volatility(9.5, f, k, 0.0, true), VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+        assert_eq!(implied_black_volatility_sqrt_expiry(10.0, f, k, 0.0, true), 0.0);
+        assert_eq!(implied_black_volatility_checked(10.0, f, k, 0.0, true), Ok(0.0));
+        let error = implied_black_volatility_checked(10.5, f, k, 0.0, true).unwrap_err();
+        assert_eq!(error, ImpliedVolError::TimeValueAtZeroExpiry);
+        assert_eq!(error.to_string(), "option price is above the intrinsic value at zero expiry");
+        assert_eq!(implied_black_volatility_checked(0.5, f, k, 0.0, false), Err(ImpliedVolError::TimeValueAtZeroExpiry));
+        assert_eq!(implied_black_volatility_checked(9.5, f, k, 0.0, true), Err(ImpliedVolError::PriceOutOfRange { intrinsic: 10.0, max: f }));
+        assert_eq!(implied_black_volatility_checked(f, f, k, 0.0, true), Err(ImpliedVolError::PriceOutOfRange { intrinsic: 10.0, max: f }));
+    }
+
+    #[test]
+    fn propagate_returns_nan_for_invalid_inputs() {
+        let (f, k, t) = (100.0, 90.0, 1.0);
+        for price in [12.0, 20.0, 50.0, 99.0] {
+            assert_eq!(implied_black_volatility_propagate(price, f, k, t, true), implied_black_volatility_checked(price, f, k, t, true).unwrap());
+        }
+        for (price, f, k, t) in [(5.0, f, k, t), (150.0, f, k, t), (f64::NAN, f, k, t), (20.0, f64::INFINITY, k, t), (20.0, f, f64::NAN, t), (20.0, f, k, f64::NAN)] {
+            assert!(implied_black_volatility_propagate(price, f, k, t, true).is_nan());
+        }
+    }
+
+    #[test]
+    fn quote_diagnostics_solvable_agrees_with_solver() {
+        let (f, k, t) = (100.0, 90.0, 1.0);
+        for q in [true, false] {
+            for price in [-1.0, 0.0, 5.0, 10.0, 10.5, 20.0, 89.9, 90.0, 99.9, 100.0, 150.0] {
+                let diagnostics = quote_diagnostics(price, f, k, t, q);
+                assert_eq!(diagnostics.solvable, implied_black_volatility(price, f, k, t, q).is_finite());
+                assert_eq!(diagnostics.time_value, price - diagnostics.intrinsic);
+                if diagnostics.solvable {
+                    assert!((0.0..1.0).contains(&diagnostics.fraction_of_band));
+                }
+            }
+        }
+        assert!(!quote_diagnostics(f64::NAN, f, k, t, true).solvable);
+        for q in [true, false] {
+            for (f, k, t) in [(f, k, -1.0), (-f, k, t), (0.0, k, t), (f, -k, t), (f, 0.0, t)] {
+                assert!(!quote_diagnostics(10.0, f, k, t, q).solvable, "{f} {k} {t} {q}");
+            }
+        }
+        let diagnostics = quote_diagnostics(55.0, f, k, t, true);
+        assert_eq!((diagnostics.intrinsic, diagnostics.supremum, diagnostics.time_value, diagnostics.fraction_of_band), (10.0, 100.0, 45.0, 0.5));
+    }
+}
@@ -48,108 +48,2604 @@
 //! ```
 //!
 //! For detailed explanations of each feature, please refer to the README.md file.
+//!
+//! # Thread safety
+//!
+//! All functions are pure: they keep no global, thread-local or cached state, so they can be called concurrently from
+//! any number of threads. All public types are `Send + Sync`, which is checked by the crate's tests.
 
+mod accuracy;
+mod arbitrage;
 mod bachelier;
 mod constants;
+mod conventions;
 mod erf_cody;
+mod greeks;
 mod lets_be_rational;
+mod model;
 mod normal_distribution;
+mod option_kind;
+mod pricer;
 mod rational_cubic;
+#[cfg(test)]
+mod reference;
+mod surface;
+
+pub use accuracy::AccuracyReport;
+pub use bachelier::NormalVolError;
+pub use conventions::DayCount;
+#[cfg(feature = "error-function")]
+pub use erf_cody::{ErfcxOverflow, ErfcxPolicy};
+pub use greeks::BlackScholesGreeks;
+pub use lets_be_rational::{
+    BlackSolver, Convergence, Diagnostics, ImpliedVolError, QuoteDiagnostics, Region, RoundTrip,
+};
+pub use model::Model;
+pub use option_kind::OptionKind;
+pub use pricer::{BachelierPricer, BlackScholesPricer, Pricer};
+pub use surface::VolSurface;
+
+/// Calculates the implied black volatility using a transformed rational guess with limited iterations.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied black volatility.
+/// A price below the intrinsic value yields `f64::NEG_INFINITY`, unless it is within 4 ulps of it, in which case it is
+/// taken as the rounded intrinsic value and yields `0.0`.
+/// If `expiry` is infinite, the result is `0.0` for every price from the intrinsic value up to (but excluding) the
+/// maximum attainable price, which is the forward for calls and the strike for puts.
+/// If `expiry` is zero, the intrinsic value is the only attainable price: it yields `0.0`, and a price above it yields
+/// `f64::INFINITY`.
+///
+/// This function does not panic for finite inputs with positive `forward` and `strike` and non-negative `expiry`.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// ```
+#[inline]
+pub fn implied_black_volatility(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility(option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility with the option type fixed at compile time.
+///
+/// The result is the same as that of [`implied_black_volatility`], including the signal values, for call sites where
+/// the option type is known statically. The option type stays a const parameter through the solver, so each
+/// instantiation solves for one option type without branching on it at run time. The result matches that function bit
+/// for bit.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The implied black volatility of the call if `IS_CALL` is true, and of the put otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_const::<true>(20.0, 100.0, 90.0, 30.0);
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// ```
+#[inline]
+pub fn implied_black_volatility_const<const IS_CALL: bool>(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_const::<IS_CALL>(
+        option_price,
+        forward,
+        strike,
+        expiry,
+    )
+}
+
+/// Calculates the implied black volatility together with every iterate of the solver, for diagnosing bad quotes.
+///
+/// The result is the same as that of [`implied_black_volatility`], which does not pay for the trace.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// `Some((volatility, trace))`, where `trace` holds the initial guess followed by the volatility after each Householder
+/// step (and the bisection result, if the iteration had to fall back to it), so that its last element is `volatility`.
+/// The trace is empty if the volatility was found without iterating, e.g. for a price equal to the intrinsic value.
+/// `None` if the implied volatility is not finite.
+///
+/// # Examples
+///
+/// ```
+/// let (black_vol, trace) = implied_vol::implied_black_volatility_traced(20.0, 100.0, 90.0, 30.0, true).unwrap();
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// assert_eq!(trace.last(), Some(&black_vol));
+/// assert_eq!(implied_vol::implied_black_volatility_traced(100.0, 100.0, 90.0, 30.0, true), None);
+/// ```
+pub fn implied_black_volatility_traced(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<(f64, Vec<f64>)> {
+    lets_be_rational::implied_black_volatility_traced(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the volatility at which a European option has a given Black-Scholes delta, e.g. to build a sticky-delta
+/// surface.
+///
+/// The delta `N(d1)` of a call, or `-N(-d1)` of a put, fixes `d1 = x / s + s / 2` with `x = ln(F / K)` and
+/// `s = σ√T`, which is solved for `s` in closed form. For strikes at or above the forward, delta is monotone in the
+/// volatility and the solution is unique. For strikes below the forward, the delta of a call first falls as the
+/// volatility rises, to `N(√(2x))` at `s = √(2x)`, and then rises again, so a target delta is met by two volatilities
+/// or none; the smaller one is returned. Puts behave likewise, with `-N(-√(2x))`. Targets close to `0` or `±1`
+/// determine the volatility poorly, as delta is then flat in it.
+///
+/// # Arguments
+///
+/// * `target_delta` - The delta, in `(0, 1)` for calls and `(-1, 0)` for puts.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The volatility, or `None` if no positive volatility attains the target delta.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_vol_for_target_delta(0.25, 100.0, 120.0, 1.0, true).unwrap();
+/// let delta = implied_vol::black_greeks(100.0, 120.0, black_vol, 1.0, true).delta;
+/// assert!((delta - 0.25).abs() <= 1e-15);
+/// assert_eq!(implied_vol::implied_vol_for_target_delta(0.5, 100.0, 90.0, 1.0, true), None);
+/// ```
+#[inline]
+pub fn implied_vol_for_target_delta(
+    target_delta: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    greeks::implied_vol_for_target_delta(target_delta, forward, strike, expiry, is_call)
+}
+
+/// Runs round trips between prices and volatilities over a fixed grid of options, e.g. to check the accuracy of the
+/// installed build at startup.
+///
+/// The grid covers out-of-the-money calls and puts under the Black and Bachelier models at moderate moneyness, and is
+/// small enough to run on every startup.
+///
+/// # Returns
+///
+/// The largest relative errors found, as an [`AccuracyReport`].
+///
+/// # Examples
+///
+/// ```
+/// let report = implied_vol::verify_accuracy();
+/// assert!(report.is_within_bound());
+/// assert!(report.black_vol <= implied_vol::AccuracyReport::BOUND);
+/// ```
+#[inline]
+pub fn verify_accuracy() -> AccuracyReport {
+    accuracy::verify_accuracy()
+}
+
+/// Calculates the initial guess of the implied black volatility from which [`implied_black_volatility`] starts its
+/// Householder refinement, e.g. to seed a solver of one's own.
+///
+/// The guess interpolates the normalised volatility by rational cubics, on the lower and upper branches through maps
+/// that make the normalised price nearly linear, and is only an approximation: typically within a few percent of the
+/// implied volatility and within about 10% across the range of inputs, which two Householder steps of third order
+/// refine to machine precision. For prices too small to be represented as normal floating-point numbers, the guess
+/// may be zero.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The initial guess of the volatility, or `None` if the price has no finite implied volatility.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 120.0, 0.3, 1.0, true);
+/// let guess = implied_vol::black_vol_initial_guess(price, 100.0, 120.0, 1.0, true).unwrap();
+/// assert!((guess - 0.3).abs() <= 0.1 * 0.3);
+/// ```
+#[inline]
+pub fn black_vol_initial_guess(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    lets_be_rational::black_vol_initial_guess(option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility together with the black vega at it, e.g. to weight the residuals of a
+/// calibration.
+///
+/// The vega is `√(F·K·T)` times the normalised vega at the solved normalised volatility, which saves a separate call to
+/// [`black_greeks`].
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The pair `(σ, ∂price/∂σ)`, where `σ` is the same as [`implied_black_volatility`], or `None` if the price has no
+/// finite implied volatility.
+///
+/// # Examples
+///
+/// ```
+/// let (black_vol, vega) = implied_vol::implied_black_volatility_and_vega(20.0, 100.0, 90.0, 30.0, true).unwrap();
+/// assert_eq!(black_vol, implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// let expected = implied_vol::black_greeks(100.0, 90.0, black_vol, 30.0, true).vega;
+/// assert!((vega - expected).abs() <= 1e-13 * expected);
+/// ```
+#[inline]
+pub fn implied_black_volatility_and_vega(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<(f64, f64)> {
+    lets_be_rational::implied_black_volatility_and_vega(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatility of a straddle, i.e. a call and a put with the same strike and expiry.
+///
+/// The straddle is worth `2 · otm + |F - K|`, where `otm` is the price of its out-of-the-money leg, so the volatility
+/// is found by inverting `otm = (straddle - |F - K|) / 2`. This avoids inverting the in-the-money leg, whose price is
+/// dominated by its intrinsic value. At the money, `otm` is half the straddle.
+///
+/// # Arguments
+///
+/// * `straddle_price` - The current price of the straddle.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the straddle.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The implied volatility, or `None` where [`implied_black_volatility_checked`] rejects the out-of-the-money leg, e.g.
+/// if `straddle_price` is below `|F - K|`.
+///
+/// # Examples
+///
+/// ```
+/// let call = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 100.0, 0.2, 1.0, true);
+/// let put = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 100.0, 0.2, 1.0, false);
+/// let black_vol = implied_vol::implied_black_vol_from_straddle(call + put, 100.0, 100.0, 1.0).unwrap();
+/// assert!((black_vol - 0.2).abs() <= 1e-15);
+/// assert_eq!(implied_vol::implied_black_vol_from_straddle(9.0, 100.0, 90.0, 1.0), None);
+/// ```
+#[inline]
+pub fn implied_black_vol_from_straddle(
+    straddle_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+) -> Option<f64> {
+    lets_be_rational::implied_black_volatility_from_straddle(
+        straddle_price,
+        forward,
+        strike,
+        expiry,
+    )
+}
+
+/// Calculates the median implied black volatility of several quotes for the same option, e.g. from different sources.
+///
+/// Each price is inverted with [`implied_black_volatility_checked`], and the prices it rejects are ignored, so that a
+/// single bad quote neither aborts the computation nor skews the result. The median is found by selection rather than
+/// sorting, and is the mean of the two middle volatilities for an even number of valid quotes.
+///
+/// # Arguments
+///
+/// * `prices` - The quoted prices of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The median implied volatility, or `None` if no price is valid.
+///
+/// # Examples
+///
+/// ```
+/// let price = |sigma| implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, sigma, 1.0, true);
+/// let prices = [price(0.19), price(0.2), 100.5, price(0.3)];
+/// let vol = implied_vol::implied_black_vol_median(&prices, 100.0, 90.0, 1.0, true).unwrap();
+/// assert!((vol - 0.2).abs() <= 1e-14);
+/// assert_eq!(implied_vol::implied_black_vol_median(&[100.5], 100.0, 90.0, 1.0, true), None);
+/// ```
+#[inline]
+pub fn implied_black_vol_median(
+    prices: &[f64],
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    lets_be_rational::implied_black_volatility_median(prices, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility, reporting invalid inputs as errors instead of signal values.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The same implied black volatility as [`implied_black_volatility`], or an [`ImpliedVolError`]:
+/// [`ImpliedVolError::PriceOutOfRange`] carries the intrinsic value and the maximum attainable price, i.e. the forward
+/// for calls and the strike for puts, so that callers can tell by how much a quote violates the bounds. At zero expiry,
+/// a price above the intrinsic value but below the maximum is reported as [`ImpliedVolError::TimeValueAtZeroExpiry`].
+/// A negative expiry and a forward or strike that is not positive are reported by their own variants.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::ImpliedVolError;
+///
+/// let black_vol = implied_vol::implied_black_volatility_checked(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, Ok(0.07011701801482094));
+///
+/// let black_vol = implied_vol::implied_black_volatility_checked(100.3, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, Err(ImpliedVolError::PriceOutOfRange { intrinsic: 10.0, max: 100.0 }));
+/// ```
+#[inline]
+pub fn implied_black_volatility_checked(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Result<f64, ImpliedVolError> {
+    lets_be_rational::implied_black_volatility_checked(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatility, returning `f64::NAN` for every input that
+/// [`implied_black_volatility_checked`] rejects.
+///
+/// Unlike [`implied_black_volatility`], which signals prices out of range with infinities, all failures map to NaN,
+/// so that columnar buffers of results can be masked uniformly afterwards.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied black volatility, or `f64::NAN` if the inputs are not finite or the price is out of range.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_propagate(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// assert!(implied_vol::implied_black_volatility_propagate(100.3, 100.0, 90.0, 30.0, true).is_nan());
+/// assert!(implied_vol::implied_black_volatility_propagate(f64::NAN, 100.0, 90.0, 30.0, true).is_nan());
+/// ```
+#[inline]
+pub fn implied_black_volatility_propagate(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_propagate(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Inverts an arbitrary price function that is increasing in the volatility, such as a black price wrapped with
+/// discounting.
+///
+/// The root is bracketed by `[lower, upper]` and refined by regula falsi with the Illinois modification, so only
+/// evaluations of `price_fn` are needed. Unlike [`implied_black_volatility`], it is neither seeded by a rational guess
+/// nor polished by Householder steps, as both rely on the closed form of the black price and its derivatives, which an
+/// arbitrary price function does not provide. It is therefore considerably slower, and [`implied_black_volatility`]
+/// should be preferred for undiscounted black prices.
+///
+/// # Arguments
+///
+/// * `target_price` - The price to match.
+/// * `price_fn` - The price as a function of the volatility, non-decreasing on `[lower, upper]`.
+/// * `lower` - The lower end of the volatility bracket, which must be finite.
+/// * `upper` - The upper end of the volatility bracket, which must be finite.
+///
+/// # Returns
+///
+/// The volatility at which `price_fn` matches `target_price` to machine precision, or `None` if `target_price` lies
+/// outside `[price_fn(lower), price_fn(upper)]`, if `lower` or `upper` is not finite, or if `target_price` or an
+/// evaluated price is NaN.
+///
+/// # Examples
+///
+/// ```
+/// let discount = (-0.05_f64).exp();
+/// let price_fn = |sigma| discount * implied_vol::calculate_european_option_price_by_black_scholes(100.0, 110.0, sigma, 1.0, true);
+/// let target_price = price_fn(0.3);
+/// let sigma = implied_vol::implied_vol_generic(target_price, price_fn, 0.0, 5.0).unwrap();
+/// assert!((sigma - 0.3).abs() <= 1e-12);
+/// assert_eq!(implied_vol::implied_vol_generic(target_price, price_fn, 0.4, 5.0), None);
+/// ```
+#[inline]
+pub fn implied_vol_generic<F: Fn(f64) -> f64>(
+    target_price: f64,
+    price_fn: F,
+    lower: f64,
+    upper: f64,
+) -> Option<f64> {
+    lets_be_rational::implied_volatility_by_inversion(target_price, price_fn, lower, upper)
+}
+
+/// Calculates the implied volatility of an option under the given model, with the validation of the model's checked
+/// solver.
+///
+/// # Arguments
+///
+/// * `model` - The pricing model. [`Model::Black`] yields a lognormal volatility as [`implied_black_volatility`]
+///   does, and [`Model::Bachelier`] a normal volatility as [`implied_normal_volatility`] does.
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied volatility, or `None` where [`implied_black_volatility_checked`] or
+/// [`implied_normal_volatility_checked`] returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Model;
+///
+/// let black_vol = implied_vol::implied_volatility(Model::Black, 20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, Some(0.07011701801482094));
+/// let normal_vol = implied_vol::implied_volatility(Model::Bachelier, 20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(normal_vol, Some(6.614292466299764));
+/// assert_eq!(implied_vol::implied_volatility(Model::Black, 5.0, 100.0, 90.0, 30.0, true), None);
+/// ```
+#[inline]
+pub fn implied_volatility(
+    model: Model,
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    model::implied_volatility(model, option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility, or the implied normal volatility where the forward or the strike is too
+/// small for a lognormal volatility to be meaningful, e.g. for interest rates near zero.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `forward_floor` - The level below which `forward` or `strike` selects [`Model::Bachelier`].
+///
+/// # Returns
+///
+/// The model used and its implied volatility, or `None` where [`implied_volatility`] returns `None` for that model.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Model;
+///
+/// let (model, black_vol) = implied_vol::implied_vol_adaptive(20.0, 100.0, 90.0, 30.0, true, 1e-4).unwrap();
+/// assert_eq!((model, black_vol), (Model::Black, 0.07011701801482094));
+/// let price = implied_vol::calculate_european_option_price_by_bachelier(0.00005, 0.0002, 0.006, 1.0, false);
+/// let (model, normal_vol) = implied_vol::implied_vol_adaptive(price, 0.00005, 0.0002, 1.0, false, 1e-4).unwrap();
+/// assert_eq!(model, Model::Bachelier);
+/// assert!((normal_vol - 0.006).abs() <= 1e-15);
+/// ```
+#[inline]
+pub fn implied_vol_adaptive(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+    forward_floor: f64,
+) -> Option<(Model, f64)> {
+    model::implied_volatility_adaptive(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+        forward_floor,
+    )
+}
+
+/// Locates an option price within the range of prices the black model can attain.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The [`QuoteDiagnostics`] of the price, whose `solvable` flag tells whether [`implied_black_volatility_checked`]
+/// succeeds.
+///
+/// # Examples
+///
+/// ```
+/// let diagnostics = implied_vol::black_quote_diagnostics(55.0, 100.0, 90.0, 1.0, true);
+/// assert_eq!((diagnostics.intrinsic, diagnostics.supremum, diagnostics.time_value), (10.0, 100.0, 45.0));
+/// assert_eq!(diagnostics.fraction_of_band, 0.5);
+/// assert!(diagnostics.solvable);
+/// assert!(!implied_vol::black_quote_diagnostics(5.0, 100.0, 90.0, 1.0, true).solvable);
+/// ```
+#[inline]
+pub fn black_quote_diagnostics(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> QuoteDiagnostics {
+    lets_be_rational::quote_diagnostics(option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility, taking the square root of the expiry.
+///
+/// Callers that already hold `√T` skip the square root taken by [`implied_black_volatility`], whose result this
+/// matches bit for bit for `sqrt_expiry = expiry.sqrt()`.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `sqrt_expiry` - The square root of the time to expiration in years, which must be finite and non-negative.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The same implied black volatility as [`implied_black_volatility`].
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_sqrt_expiry(20.0, 100.0, 90.0, 30.0_f64.sqrt(), true);
+/// assert_eq!(black_vol, implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// ```
+#[inline]
+pub fn implied_black_volatility_sqrt_expiry(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    sqrt_expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_sqrt_expiry(
+        option_price,
+        forward,
+        strike,
+        sqrt_expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatilities of a fixed number of options, e.g. a tile of a batched kernel.
+///
+/// The length is known at compile time, which lets the compiler unroll the loop after inlining. Each element equals
+/// the result of [`implied_black_volatility`] on the corresponding inputs.
+///
+/// # Arguments
+///
+/// * `option_prices` - The current prices of the options.
+/// * `forwards` - The current forward prices of the underlying assets.
+/// * `strikes` - The strike prices of the options.
+/// * `expiries` - The times to expiration in years.
+/// * `is_call` - Flags indicating whether each option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied black volatility of the `i`-th option at index `i`.
+///
+/// # Examples
+///
+/// ```
+/// let vols = implied_vol::implied_black_volatility_array(
+///     [20.0, 20.0],
+///     [100.0, 100.0],
+///     [90.0, 110.0],
+///     [30.0, 30.0],
+///     [true, false],
+/// );
+/// assert_eq!(vols[0], implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// assert_eq!(vols[1], implied_vol::implied_black_volatility(20.0, 100.0, 110.0, 30.0, false));
+/// ```
+#[inline]
+pub fn implied_black_volatility_array<const N: usize>(
+    option_prices: [f64; N],
+    forwards: [f64; N],
+    strikes: [f64; N],
+    expiries: [f64; N],
+    is_call: [bool; N],
+) -> [f64; N] {
+    lets_be_rational::implied_black_volatility_array(
+        option_prices,
+        forwards,
+        strikes,
+        expiries,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatilities of options sharing the same forward and expiry, e.g. a volatility smile.
+///
+/// The square roots of `forward` and `expiry` are computed once for the whole slice.
+///
+/// # Arguments
+///
+/// * `forward` - The current forward price of the underlying asset.
+/// * `expiry` - The time to expiration in years.
+/// * `strikes` - The strike prices of the options.
+/// * `option_prices` - The current prices of the options.
+/// * `is_call` - Flags indicating whether each option is a call (true) or put (false).
+/// * `out` - The buffer receiving the implied black volatility of the `i`-th option at index `i`.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = [0.0; 2];
+/// implied_vol::implied_black_vol_smile(100.0, 30.0, &[90.0, 110.0], &[20.0, 20.0], &[true, false], &mut out);
+/// assert_eq!(out[0], implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// assert_eq!(out[1], implied_vol::implied_black_volatility(20.0, 100.0, 110.0, 30.0, false));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the slices have different lengths.
+#[inline]
+pub fn implied_black_vol_smile(
+    forward: f64,
+    expiry: f64,
+    strikes: &[f64],
+    option_prices: &[f64],
+    is_call: &[bool],
+    out: &mut [f64],
+) {
+    lets_be_rational::implied_black_volatility_smile(
+        forward,
+        expiry,
+        strikes,
+        option_prices,
+        is_call,
+        out,
+    );
+}
+
+/// Calculates the implied black volatilities of a slice of options, stopping the refinement of each once it is
+/// accurate to a relative tolerance.
+///
+/// Full precision usually takes two Householder steps; a tolerance such as `1e-8` often lets the second one be
+/// skipped, which pays off when inverting large batches of quotes.
+///
+/// # Arguments
+///
+/// * `option_prices` - The current prices of the options.
+/// * `forwards` - The current forward prices of the underlying assets.
+/// * `strikes` - The strike prices of the options.
+/// * `expiries` - The times to expiration in years.
+/// * `is_call` - Flags indicating whether each option is a call (true) or put (false).
+/// * `rel_tol` - The relative accuracy required of each volatility. Values at or below `f64::EPSILON` give the
+///   same results as [`implied_black_volatility`].
+/// * `out` - The buffer receiving the implied black volatility of the `i`-th option at index `i`.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 110.0, 0.3, 0.5, true);
+/// let mut out = [0.0; 1];
+/// implied_vol::implied_black_volatility_slice_tol(&[price], &[100.0], &[110.0], &[0.5], &[true], 1e-8, &mut out);
+/// assert!((out[0] - 0.3).abs() <= 1e-8 * 0.3);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the slices have different lengths.
+#[inline]
+pub fn implied_black_volatility_slice_tol(
+    option_prices: &[f64],
+    forwards: &[f64],
+    strikes: &[f64],
+    expiries: &[f64],
+    is_call: &[bool],
+    rel_tol: f64,
+    out: &mut [f64],
+) {
+    lets_be_rational::implied_black_volatility_slice_tol(
+        option_prices,
+        forwards,
+        strikes,
+        expiries,
+        is_call,
+        rel_tol,
+        out,
+    );
+}
+
+/// Calculates the implied black volatility with a caller-chosen cap on the number of Householder steps,
+/// reporting whether the refinement converged within it.
+///
+/// [`implied_black_volatility`] uses a cap of 2, which suffices for full attainable precision.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `max_iterations` - The maximum number of Householder steps.
+///
+/// # Returns
+///
+/// A tuple of the implied black volatility and the [`Diagnostics`] of the solve.
+/// When the convergence is [`Convergence::MaxIterationsReached`], the volatility may not be fully polished.
+/// When it is [`Convergence::BisectionFallback`], the Householder steps diverged and the volatility was found by
+/// bisection.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Convergence;
+///
+/// let (black_vol, diagnostics) = implied_vol::implied_black_volatility_with_diagnostics(20.0, 100.0, 90.0, 30.0, true, 2);
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// assert_eq!(diagnostics.convergence, Convergence::Converged);
+///
+/// let (_, diagnostics) = implied_vol::implied_black_volatility_with_diagnostics(20.0, 100.0, 90.0, 30.0, true, 0);
+/// assert_eq!(diagnostics.convergence, Convergence::MaxIterationsReached);
+/// ```
+#[inline]
+pub fn implied_black_volatility_with_diagnostics(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+    max_iterations: u8,
+) -> (f64, Diagnostics) {
+    lets_be_rational::implied_black_volatility_with_diagnostics(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+        max_iterations,
+    )
+}
+
+/// Calculates the implied black volatility starting the Householder steps from a caller-supplied volatility instead
+/// of the rational-cubic initial guess.
+///
+/// This pays off for warm starts, e.g. the volatility solved at a neighbouring strike during a calibration. The steps
+/// are capped at 2 as in [`implied_black_volatility`], so a poor guess may leave the result unpolished, which the
+/// returned [`Diagnostics`] report.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `initial_guess` - The volatility to start from. If it is not finite and positive, the rational-cubic initial
+///   guess is used instead.
+///
+/// # Returns
+///
+/// A tuple of the implied black volatility and the [`Diagnostics`] of the solve.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Convergence;
+///
+/// let (black_vol, diagnostics) = implied_vol::implied_black_volatility_with_initial_guess(20.0, 100.0, 90.0, 30.0, true, 0.07);
+/// assert!((black_vol - 0.07011701801482094).abs() <= 1e-15);
+/// assert_eq!(diagnostics.convergence, Convergence::Converged);
+/// ```
+#[inline]
+pub fn implied_black_volatility_with_initial_guess(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+    initial_guess: f64,
+) -> (f64, Diagnostics) {
+    lets_be_rational::implied_black_volatility_with_initial_guess(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+        initial_guess,
+    )
+}
+
+/// Calculates the implied black volatility from an option price quoted as a fraction of the forward.
+///
+/// # Arguments
+///
+/// * `relative_price` - The current price of the option divided by `forward`.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The same as [`implied_black_volatility`] with `option_price = relative_price * forward`.
+/// In particular, a call `relative_price` outside `[0, 1)` yields one of the signal values, since the price is then
+/// below the intrinsic value or at or above the forward.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_relative(0.2, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// ```
+#[inline]
+pub fn implied_black_volatility_relative(
+    relative_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_relative(
+        relative_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Approximates the implied black volatility, e.g. to seed another solver.
+///
+/// The rational initial guess of [`implied_black_volatility`] is followed by a single Householder step instead of two.
+/// The guess alone can be several percent off, but after one step the relative error is typically around `1e-6` and
+/// stays below `1e-5` for moneyness `F/K` between `0.01` and `100` and total volatility `σ√T` between `0.001` and `4.5`.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The approximate implied black volatility, or the same signal values as [`implied_black_volatility`].
+///
+/// # Examples
+///
+/// ```
+/// let approximation = implied_vol::implied_black_vol_approx(20.0, 100.0, 90.0, 30.0, true);
+/// let exact = implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true);
+/// assert!(((approximation - exact) / exact).abs() < 1e-5);
+/// ```
+#[inline]
+pub fn implied_black_vol_approx(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_approximation(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatility of an option whose strike is given by its log-moneyness `ln(strike / forward)`.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `log_moneyness` - The log-moneyness `ln(strike / forward)` of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The same as [`implied_black_volatility`] with `strike = forward * exp(log_moneyness)`.
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_from_log_moneyness(5.0, 100.0, 0.0, 1.0, true);
+/// assert_eq!(black_vol, implied_vol::implied_black_volatility(5.0, 100.0, 100.0, 1.0, true));
+/// ```
+#[inline]
+pub fn implied_black_volatility_from_log_moneyness(
+    option_price: f64,
+    forward: f64,
+    log_moneyness: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_from_log_moneyness(
+        option_price,
+        forward,
+        log_moneyness,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatility and polishes it with additional Newton steps on the repricing error.
+///
+/// [`implied_black_volatility`] is already accurate to a few ULPs. The extra steps squeeze out part of the remaining
+/// error, most notably for in-the-money options, where the repricing error is reduced by about a third on average.
+/// A step is only taken if it reduces the repricing error, so the result is never worse than the base method.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `extra_newton_steps` - The maximum number of Newton steps taken after the base method.
+///
+/// # Returns
+///
+/// The implied black volatility, or the same signal values as [`implied_black_volatility`].
+///
+/// # Examples
+///
+/// ```
+/// let black_vol = implied_vol::implied_black_volatility_refined(20.0, 100.0, 90.0, 30.0, true, 2);
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, black_vol, 30.0, true);
+/// assert!((price - 20.0).abs() <= 2.0 * f64::EPSILON * 20.0);
+/// ```
+#[inline]
+pub fn implied_black_volatility_refined(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+    extra_newton_steps: u32,
+) -> f64 {
+    lets_be_rational::implied_black_volatility_refined(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+        extra_newton_steps,
+    )
+}
+
+/// Calculates the interval of implied black volatilities consistent with an option price known to within a tolerance.
+///
+/// The width of the interval indicates how well the price determines the volatility: it is narrow near the money and
+/// widens where vega is small, e.g. deep out of the money.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `eps` - The absolute price tolerance, which must be non-negative.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// `None` if `option_price - eps` is below the intrinsic value.
+/// Otherwise, the implied black volatilities of `option_price - eps` and `option_price + eps`; the latter is infinite if
+/// `option_price + eps` is at or above the maximum price.
+///
+/// # Examples
+///
+/// ```
+/// let (lower, upper) = implied_vol::black_vol_interval(20.0, 1e-6, 100.0, 90.0, 30.0, true).unwrap();
+/// assert!(lower < 0.07011701801482094 && 0.07011701801482094 < upper);
+/// assert_eq!(implied_vol::black_vol_interval(10.0, 1e-6, 100.0, 90.0, 30.0, true), None);
+/// ```
+#[inline]
+pub fn black_vol_interval(
+    option_price: f64,
+    eps: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<(f64, f64)> {
+    lets_be_rational::black_vol_interval(option_price, eps, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied black volatility together with the absolute error of repricing the option at that volatility.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// A tuple `(volatility, error)` where `volatility` is the result of [`implied_black_volatility`] and
+/// `error` is `|option_price - calculate_european_option_price_by_black_scholes(.., volatility, ..)|`.
+///
+/// # Examples
+///
+/// ```
+/// let (black_vol, error) = implied_vol::implied_black_volatility_with_repricing_error(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, 0.07011701801482094);
+/// assert!(error <= 2.0 * f64::EPSILON * 20.0);
+/// ```
+#[inline]
+pub fn implied_black_volatility_with_repricing_error(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> (f64, f64) {
+    lets_be_rational::implied_black_volatility_with_repricing_error(
+        option_price,
+        forward,
+        strike,
+        expiry,
+        is_call,
+    )
+}
+
+/// Calculates the implied black volatility and reprices the option at it in one call.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// `Some(RoundTrip)` holding the implied volatility, the repriced option and the relative repricing error, or `None`
+/// if no finite implied volatility exists.
+///
+/// # Examples
+///
+/// ```
+/// let round_trip = implied_vol::verify_black_roundtrip(20.0, 100.0, 90.0, 30.0, true).unwrap();
+/// assert_eq!(round_trip.vol, 0.07011701801482094);
+/// assert!(round_trip.rel_error <= 1e-12);
+/// assert!(implied_vol::verify_black_roundtrip(5.0, 100.0, 90.0, 30.0, true).is_none());
+/// ```
+#[inline]
+pub fn verify_black_roundtrip(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<RoundTrip> {
+    lets_be_rational::verify_black_roundtrip(option_price, forward, strike, expiry, is_call)
+}
+
+/// Prices an option at a volatility, inverts the price and returns the relative error in the recovered volatility,
+/// `|σ - implied(price(σ))| / σ`.
+///
+/// For out-of-the-money options with `|ln(F/K)| <= 0.7` and `σ√T >= 0.05`, the error is below `1e-14`. It grows
+/// where the price carries little information about the volatility: for in-the-money options whose time value is lost
+/// to rounding against the intrinsic value, and for prices that underflow.
+///
+/// # Arguments
+///
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility to price at.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The relative error of the recovered volatility.
+///
+/// # Examples
+///
+/// ```
+/// assert!(implied_vol::black_vol_roundtrip_error(100.0, 120.0, 0.2, 1.0, true) <= 1e-14);
+/// ```
+#[inline]
+pub fn black_vol_roundtrip_error(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::black_vol_roundtrip_error(forward, strike, volatility, expiry, is_call)
+}
+
+/// Checks a slice of undiscounted call prices across strikes for static arbitrage.
+///
+/// The prices must lie within `[max(forward - strike, 0), forward]`, decrease in the strike with slopes no steeper
+/// than `-1`, and be convex in the strike, i.e. admit no butterfly arbitrage. The comparisons are exact, so prices
+/// that are linear in the strike up to rounding may be rejected.
+///
+/// # Arguments
+///
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strikes` - The strike prices, which must be strictly increasing.
+/// * `call_prices` - The undiscounted call prices at the corresponding strikes.
+///
+/// # Returns
+///
+/// `true` if the slice is free of static arbitrage, `false` otherwise or if the strikes are not strictly increasing.
+///
+/// # Examples
+///
+/// ```
+/// let strikes = [80.0, 90.0, 100.0, 110.0, 120.0];
+/// assert!(implied_vol::is_arbitrage_free(100.0, &strikes, &[22.0, 14.0, 8.0, 4.0, 2.0]));
+/// assert!(!implied_vol::is_arbitrage_free(100.0, &strikes, &[22.0, 14.0, 9.5, 4.0, 2.0]));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `strikes` and `call_prices` have different lengths.
+#[inline]
+pub fn is_arbitrage_free(forward: f64, strikes: &[f64], call_prices: &[f64]) -> bool {
+    arbitrage::is_arbitrage_free(forward, strikes, call_prices)
+}
+
+/// Calculates the forward volatility between two expiries from the implied volatilities at them.
+///
+/// The forward variance is the increase of the total variance `σ²T` per unit of time, `(σ₂²T₂ - σ₁²T₁) / (T₂ - T₁)`,
+/// without any convexity adjustment.
+///
+/// # Arguments
+///
+/// * `sigma1` - The implied volatility at the first expiry.
+/// * `t1` - The first expiry in years.
+/// * `sigma2` - The implied volatility at the second expiry.
+/// * `t2` - The second expiry in years, after the first.
+///
+/// # Returns
+///
+/// The forward volatility, or `None` if the forward variance is negative, which is calendar arbitrage, if `t2` is
+/// not after `t1`, or if an input is NaN.
+///
+/// # Examples
+///
+/// ```
+/// let sigma = implied_vol::forward_volatility(0.2, 1.0, 0.25, 2.0).unwrap();
+/// assert!((sigma - 0.085_f64.sqrt()).abs() <= 2.0 * f64::EPSILON);
+/// assert_eq!(implied_vol::forward_volatility(0.3, 1.0, 0.2, 2.0), None);
+/// ```
+#[inline]
+pub fn forward_volatility(sigma1: f64, t1: f64, sigma2: f64, t2: f64) -> Option<f64> {
+    arbitrage::forward_volatility(sigma1, t1, sigma2, t2)
+}
+
+/// Splits the price of a European option into its intrinsic value and its time value.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// A tuple `(intrinsic, option_price - intrinsic)`, where the intrinsic value is `max(forward - strike, 0)` for a call
+/// and `max(strike - forward, 0)` for a put.
+/// A negative time value is returned as is; it signals a price below intrinsic, for which
+/// [`implied_black_volatility`] returns `f64::NEG_INFINITY`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(implied_vol::black_intrinsic_and_time_value(20.0, 100.0, 90.0, true), (10.0, 10.0));
+/// assert_eq!(implied_vol::black_intrinsic_and_time_value(9.0, 100.0, 90.0, true), (10.0, -1.0));
+/// ```
+#[inline]
+pub fn black_intrinsic_and_time_value(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    is_call: bool,
+) -> (f64, f64) {
+    lets_be_rational::intrinsic_and_time_value(option_price, forward, strike, is_call)
+}
+
+/// Calculates the forward price of an asset paying a continuous dividend yield.
+///
+/// The forward is `spot · exp((rate - dividend_yield) · expiry)`, with both rates continuously compounded.
+///
+/// # Arguments
+///
+/// * `spot` - The current spot price of the underlying asset.
+/// * `rate` - The continuously compounded risk-free interest rate.
+/// * `dividend_yield` - The continuously compounded dividend yield, or the foreign interest rate for currencies.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The forward price of the underlying asset.
+///
+/// # Examples
+///
+/// ```
+/// let forward = implied_vol::forward_from_spot(100.0, 0.05, 0.02, 1.0);
+/// assert_eq!(forward, 100.0 * 0.03_f64.exp());
+/// assert_eq!(implied_vol::forward_from_spot(100.0, 0.05, 0.05, 1.0), 100.0);
+/// ```
+#[inline]
+pub fn forward_from_spot(spot: f64, rate: f64, dividend_yield: f64, expiry: f64) -> f64 {
+    conventions::forward_from_spot(spot, rate, dividend_yield, expiry)
+}
+
+/// Calculates the spot price of an asset paying a continuous dividend yield from its forward price.
+///
+/// This is the inverse of [`forward_from_spot`]: `forward · exp((dividend_yield - rate) · expiry)`.
+///
+/// # Arguments
+///
+/// * `forward` - The forward price of the underlying asset.
+/// * `rate` - The continuously compounded risk-free interest rate.
+/// * `dividend_yield` - The continuously compounded dividend yield, or the foreign interest rate for currencies.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The spot price of the underlying asset.
+///
+/// # Examples
+///
+/// ```
+/// let spot = implied_vol::spot_from_forward(100.0, 0.05, 0.02, 1.0);
+/// assert_eq!(spot, 100.0 * (-0.03_f64).exp());
+/// ```
+#[inline]
+pub fn spot_from_forward(forward: f64, rate: f64, dividend_yield: f64, expiry: f64) -> f64 {
+    conventions::spot_from_forward(forward, rate, dividend_yield, expiry)
+}
+
+/// Calculates the time between two dates as a year fraction under a day count convention, e.g. for the `expiry`
+/// arguments of this crate.
+///
+/// Dates are `(year, month, day)` tuples of the proleptic Gregorian calendar and are not validated.
+///
+/// # Arguments
+///
+/// * `start` - The start date.
+/// * `end` - The end date.
+/// * `convention` - The day count convention.
+///
+/// # Returns
+///
+/// The year fraction from `start` to `end`, negative if `end` is before `start`.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::DayCount;
+///
+/// assert_eq!(implied_vol::year_fraction((2024, 1, 1), (2025, 1, 1), DayCount::Act365Fixed), 366.0 / 365.0);
+/// assert_eq!(implied_vol::year_fraction((2024, 1, 1), (2025, 1, 1), DayCount::Act360), 366.0 / 360.0);
+/// assert_eq!(implied_vol::year_fraction((2007, 1, 31), (2007, 3, 31), DayCount::Thirty360), 60.0 / 360.0);
+/// ```
+#[inline]
+pub fn year_fraction(start: (i32, u32, u32), end: (i32, u32, u32), convention: DayCount) -> f64 {
+    conventions::year_fraction(start, end, convention)
+}
+
+/// Calculates the price of a European option using the Black-Scholes formula.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration of the option.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The price of the European option.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.07011701801482094, 30.0, true);
+/// assert!((price - 20.0).abs()<= 2.0 * f64::EPSILON * 20.0);
+/// ```
+#[inline]
+pub fn calculate_european_option_price_by_black_scholes(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::black(forward, strike, volatility, expiry, is_call)
+}
+
+/// Calculates the price of a European option using the Black-Scholes formula, with the option type fixed at compile
+/// time.
+///
+/// This is the same primitive as [`calculate_european_option_price_by_black_scholes`], for call sites where the option
+/// type is known statically. The option type stays a const parameter down to the normalised black price, so each
+/// instantiation prices one option type without branching on it at run time. The result matches that function bit
+/// for bit.
+///
+/// The inputs are not validated. The result is meaningful for positive finite `forward` and `strike`, and non-negative
+/// `volatility` and `expiry`, where it lies in `[intrinsic, max)` with `max` the forward for calls and the strike for
+/// puts. Outside that domain the result is unspecified but the function does not panic.
+///
+/// # Arguments
+///
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The price of the European call if `IS_CALL` is true, and of the put otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let call = implied_vol::black_price::<true>(100.0, 90.0, 0.07011701801482094, 30.0);
+/// assert_eq!(call, implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.07011701801482094, 30.0, true));
+/// ```
+#[inline]
+pub fn black_price<const IS_CALL: bool>(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+) -> f64 {
+    lets_be_rational::black_const::<IS_CALL>(forward, strike, volatility, expiry)
+}
+
+/// Calculates the price of a European option using the Black-Scholes formula, taking the square root of the expiry.
+///
+/// Callers that already hold `√T`, e.g. for every tenor of a curve, skip the square root taken by
+/// [`calculate_european_option_price_by_black_scholes`], whose result this matches bit for bit for
+/// `sqrt_expiry = expiry.sqrt()`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `sqrt_expiry` - The square root of the time to expiration of the option, which must be finite and non-negative.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The price of the European option.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes_sqrt_expiry(100.0, 90.0, 0.2, 0.5, true);
+/// assert_eq!(price, implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.2, 0.25, true));
+/// ```
+#[inline]
+pub fn calculate_european_option_price_by_black_scholes_sqrt_expiry(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    sqrt_expiry: f64,
+    is_call: bool,
+) -> f64 {
+    lets_be_rational::black_sqrt_expiry(forward, strike, volatility, sqrt_expiry, is_call)
+}
+
+/// Calculates the Black-Scholes prices of one option over a grid of volatilities, e.g. to plot the price-volatility curve.
+///
+/// The log-moneyness, `√F·√K` and `√T` are computed once for the whole grid.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration of the option.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `volatilities` - The grid of volatilities.
+/// * `out` - The buffer receiving the price at the `i`-th volatility at index `i`.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = [0.0; 2];
+/// implied_vol::black_price_curve(100.0, 90.0, 1.0, true, &[0.1, 0.2], &mut out);
+/// assert_eq!(out[1], implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.2, 1.0, true));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `volatilities` and `out` have different lengths.
+#[inline]
+pub fn black_price_curve(
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+    volatilities: &[f64],
+    out: &mut [f64],
+) {
+    lets_be_rational::black_price_curve(forward, strike, expiry, is_call, volatilities, out);
+}
+
+/// Calculates the price of a European option using the Black-Scholes formula from the total volatility `s = σ√T`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `s` - The volatility multiplied by the square root of the time to expiration.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The price of the European option.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::black_price_from_s(100.0, 90.0, 0.07011701801482094 * 30.0_f64.sqrt(), true);
+/// assert_eq!(price, implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.07011701801482094, 30.0, true));
+/// ```
+#[inline]
+pub fn black_price_from_s(forward: f64, strike: f64, s: f64, is_call: bool) -> f64 {
+    lets_be_rational::black_from_s(forward, strike, s, is_call)
+}
+
+/// Calculates the implied total volatility `s = σ√T` from the price of a European option.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied total volatility. Dividing it by the square root of the time to expiration gives the result of
+/// [`implied_black_volatility`].
+///
+/// # Examples
+///
+/// ```
+/// let s = implied_vol::implied_s_black(20.0, 100.0, 90.0, true);
+/// assert_eq!(s / 30.0_f64.sqrt(), implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// ```
+#[inline]
+pub fn implied_s_black(option_price: f64, forward: f64, strike: f64, is_call: bool) -> f64 {
+    lets_be_rational::implied_normalised_black_volatility(option_price, forward, strike, is_call)
+}
+
+/// Calculates the total implied black volatility `s = σ√T` of an at-the-money option from its normalised price.
+///
+/// At the money, i.e. for `forward == strike`, the normalised price `β = option_price / forward` of a call or put is
+/// `2Φ(s/2) - 1`. This function inverts that relation in closed form, polished by Newton steps, instead of running the
+/// general solver.
+///
+/// # Arguments
+///
+/// * `beta` - The normalised price `option_price / forward`, which must satisfy `0 ≤ beta < 1`.
+///
+/// # Returns
+///
+/// The total implied volatility `s`; divide by `√T` to obtain `σ`.
+/// If `beta` is negative or at least `1`, the signal values `f64::NEG_INFINITY` and `f64::INFINITY` are returned,
+/// respectively.
+///
+/// # Examples
+///
+/// ```
+/// let s = implied_vol::implied_black_vol_atm_normalised(0.2);
+/// let general = implied_vol::implied_s_black(20.0, 100.0, 100.0, true);
+/// assert!((s - general).abs() <= 4.0 * f64::EPSILON * general);
+/// ```
+#[inline]
+pub fn implied_black_vol_atm_normalised(beta: f64) -> f64 {
+    lets_be_rational::implied_normalised_volatility_atm(beta)
+}
+
+/// Calculates the `d1` and `d2` terms of the Black-Scholes formula.
+///
+/// With `s = volatility * sqrt(expiry)`, they are defined as `d1 = ln(forward / strike) / s + s / 2` and `d2 = d1 - s`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration of the option.
+///
+/// # Returns
+///
+/// A tuple `(d1, d2)`.
+/// When `s` is zero, the limits are returned: `(0.0, 0.0)` if `forward == strike`, and both infinite with the sign of
+/// `ln(forward / strike)` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// let (d1, d2) = implied_vol::black_d1_d2(100.0, 100.0, 0.2, 4.0);
+/// assert_eq!((d1, d2), (0.2, -0.2));
+/// ```
+#[inline]
+pub fn black_d1_d2(forward: f64, strike: f64, volatility: f64, expiry: f64) -> (f64, f64) {
+    greeks::d1_d2(forward, strike, volatility, expiry)
+}
+
+/// Splits the Black-Scholes price of a European call option into its asset-or-nothing and cash-or-nothing legs.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration of the option.
+///
+/// # Returns
+///
+/// A tuple `(F·Φ(d1), K·Φ(d2))`. Their difference is the price of the call option.
+///
+/// # Examples
+///
+/// ```
+/// let (asset, cash) = implied_vol::black_payoff_decomposition(100.0, 90.0, 0.2, 1.0);
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.2, 1.0, true);
+/// assert!((asset - cash - price).abs() <= 4.0 * f64::EPSILON * 100.0);
+/// ```
+#[inline]
+pub fn black_payoff_decomposition(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+) -> (f64, f64) {
+    greeks::payoff_decomposition(forward, strike, volatility, expiry)
+}
+
+/// Calculates the Greeks of a European option priced by the Black-Scholes model.
+///
+/// The price is undiscounted and expressed in terms of the forward, so delta and gamma are sensitivities to the
+/// forward and theta is the negated sensitivity to the expiry.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The [`BlackScholesGreeks`] of the option.
+///
+/// # Examples
+///
+/// ```
+/// let greeks = implied_vol::black_greeks(100.0, 100.0, 0.2, 1.0, true);
+/// assert!((greeks.delta - 0.539827837277029).abs() <= 2.0 * f64::EPSILON);
+/// assert!((greeks.vega - 39.69525474770118).abs() <= 64.0 * f64::EPSILON);
+/// ```
+#[inline]
+pub fn black_greeks(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+    is_call: bool,
+) -> BlackScholesGreeks {
+    greeks::greeks(forward, strike, volatility, expiry, is_call)
+}
+
+/// Calculates the charm `∂Δ/∂T` of a European option priced by the Black-Scholes model, i.e. the change of delta as
+/// the expiry lengthens.
+///
+/// Charm is the same for calls and puts, whose deltas differ by a constant. As the expiry tends to zero it vanishes
+/// away from the money and diverges at the money, where it grows like `1 / √T`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The charm `-φ(d1) · d2 / (2T)`.
+///
+/// # Examples
+///
+/// ```
+/// let charm = implied_vol::black_charm(100.0, 100.0, 0.2, 1.0);
+/// assert!((charm - 0.019847627373850588).abs() <= 1e-15);
+/// ```
+#[inline]
+pub fn black_charm(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::charm(forward, strike, volatility, expiry)
+}
+
+/// Calculates the veta `∂vega/∂T` of a European option priced by the Black-Scholes model, i.e. the change of vega as
+/// the expiry lengthens.
+///
+/// Veta is the same for calls and puts. As the expiry tends to zero it vanishes away from the money and diverges at
+/// the money, where it grows like `1 / √T`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The veta `F · φ(d1) · (1 + d1 · d2) / (2√T)`.
+///
+/// # Examples
+///
+/// ```
+/// let veta = implied_vol::black_veta(100.0, 100.0, 0.2, 1.0);
+/// assert!((veta - 19.649151100112082).abs() <= 1e-12);
+/// ```
+#[inline]
+pub fn black_veta(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::veta(forward, strike, volatility, expiry)
+}
+
+/// Calculates the speed `∂³V/∂F³` of a European option priced by the Black-Scholes model, i.e. the sensitivity of
+/// gamma to the forward.
+///
+/// Speed is the same for calls and puts.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The speed `-Γ · (1 + d1 / (σ√T)) / F`, where `Γ` is the gamma.
+///
+/// # Examples
+///
+/// ```
+/// let speed = implied_vol::black_speed(100.0, 100.0, 0.2, 1.0);
+/// assert!((speed - -0.00029771441060775882).abs() <= 1e-18);
+/// ```
+#[inline]
+pub fn black_speed(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::speed(forward, strike, volatility, expiry)
+}
+
+/// Calculates the color `∂Γ/∂T` of a European option priced by the Black-Scholes model, i.e. the change of gamma as
+/// the expiry lengthens.
+///
+/// Color is the same for calls and puts.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The color `Γ · (d1 · d2 - 1) / (2T)`, where `Γ` is the gamma.
+///
+/// # Examples
+///
+/// ```
+/// let color = implied_vol::black_color(100.0, 100.0, 0.2, 1.0);
+/// assert!((color - -0.010023051823794547).abs() <= 1e-16);
+/// ```
+#[inline]
+pub fn black_color(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::color(forward, strike, volatility, expiry)
+}
+
+/// Calculates the ultima `∂³V/∂σ³` of a European option priced by the Black-Scholes model, i.e. the sensitivity of
+/// volga to the volatility.
+///
+/// Ultima is the same for calls and puts.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The ultima `-vega · (d1 · d2 · (1 - d1 · d2) + d1² + d2²) / σ²`.
+///
+/// # Examples
+///
+/// ```
+/// let ultima = implied_vol::black_ultima(100.0, 100.0, 0.2, 1.0);
+/// assert!((ultima - -9.824575550056041).abs() <= 1e-12);
+/// ```
+#[inline]
+pub fn black_ultima(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::ultima(forward, strike, volatility, expiry)
+}
+
+/// Calculates the zomma `∂Γ/∂σ` of a European option priced by the Black-Scholes model, i.e. the sensitivity of
+/// gamma to the volatility.
+///
+/// Zomma is the same for calls and puts.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+///
+/// # Returns
+///
+/// The zomma `Γ · (d1 · d2 - 1) / σ`, where `Γ` is the gamma.
+///
+/// # Examples
+///
+/// ```
+/// let zomma = implied_vol::black_zomma(100.0, 100.0, 0.2, 1.0);
+/// assert!((zomma - -0.10023051823794547).abs() <= 1e-15);
+/// ```
+#[inline]
+pub fn black_zomma(forward: f64, strike: f64, volatility: f64, expiry: f64) -> f64 {
+    greeks::zomma(forward, strike, volatility, expiry)
+}
+
+/// Calculates the Greeks of a European option priced by the shifted (displaced) Black model.
+///
+/// The shifted model prices with the Black-Scholes formula at the translated forward `forward + shift` and strike
+/// `strike + shift`, which allows negative rates down to `-shift`. Since the translation has unit derivative, delta,
+/// gamma and the volatility Greeks are those of the Black-Scholes model at the translated coordinates. The volatility
+/// is the shifted lognormal volatility, so vega is not comparable to the vega of an unshifted model.
+///
+/// The risk-neutral density implied by the shifted model, `∂²V/∂K² = φ(d2) / ((strike + shift)·σ√T)`, is likewise
+/// lognormal in the shifted strike coordinate `strike + shift`, not in `strike`.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset; `forward + shift` must be positive.
+/// * `strike` - The strike price of the option; `strike + shift` must be positive.
+/// * `shift` - The displacement of the model.
+/// * `volatility` - The shifted lognormal volatility, which must be positive.
+/// * `expiry` - The time to expiration of the option, which must be positive.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The [`BlackScholesGreeks`] of the option with respect to the unshifted forward.
+///
+/// # Examples
+///
+/// ```
+/// let shifted = implied_vol::black_shifted_greeks(-0.005, 0.0, 0.02, 0.3, 1.0, true);
+/// assert_eq!(shifted, implied_vol::black_greeks(0.015, 0.02, 0.3, 1.0, true));
+/// ```
+#[inline]
+pub fn black_shifted_greeks(
+    forward: f64,
+    strike: f64,
+    shift: f64,
+    volatility: f64,
+    expiry: f64,
+    is_call: bool,
+) -> BlackScholesGreeks {
+    greeks::shifted_greeks(forward, strike, shift, volatility, expiry, is_call)
+}
+
+/// Calculates the Greeks of many European options priced by the Black-Scholes model, one per index.
+///
+/// Each element equals the result of [`black_greeks`] on the inputs at the same index.
+///
+/// # Arguments
+///
+/// * `forwards` - The current values of the underlying assets.
+/// * `strikes` - The strike prices of the options.
+/// * `volatilities` - The volatilities of the underlying assets.
+/// * `expiries` - The times to expiration of the options.
+/// * `is_call` - Flags indicating whether each option is a call (true) or put (false).
+///
+/// # Examples
+///
+/// ```
+/// let grid = implied_vol::greeks_grid(&[100.0, 100.0], &[90.0, 110.0], &[0.2, 0.25], &[1.0, 2.0], &[true, false]);
+/// assert_eq!(grid[1], implied_vol::black_greeks(100.0, 110.0, 0.25, 2.0, false));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the slices have different lengths.
+#[inline]
+pub fn greeks_grid(
+    forwards: &[f64],
+    strikes: &[f64],
+    volatilities: &[f64],
+    expiries: &[f64],
+    is_call: &[bool],
+) -> Vec<BlackScholesGreeks> {
+    greeks::greeks_grid(forwards, strikes, volatilities, expiries, is_call)
+}
+
+/// Calculates the Black-Scholes vegas of options sharing the same forward and expiry, e.g. for a vega ladder by strike.
+///
+/// Vega does not depend on whether the option is a call or a put. The square roots of `forward` and `expiry` are
+/// computed once for the whole ladder.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `expiry` - The time to expiration of the options.
+/// * `strikes` - The strike prices of the options.
+/// * `volatilities` - The volatilities at the corresponding strikes.
+///
+/// # Returns
+///
+/// The vega `∂V/∂σ` at each strike.
+///
+/// # Examples
+///
+/// ```
+/// let ladder = implied_vol::vega_ladder(100.0, 1.0, &[90.0, 100.0], &[0.25, 0.2]);
+/// assert!((ladder[1] - implied_vol::black_greeks(100.0, 100.0, 0.2, 1.0, true).vega).abs() <= 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `strikes` and `volatilities` have different lengths.
+#[inline]
+pub fn vega_ladder(forward: f64, expiry: f64, strikes: &[f64], volatilities: &[f64]) -> Vec<f64> {
+    greeks::vega_ladder(forward, expiry, strikes, volatilities)
+}
+
+/// Calculates the vega-weighted mean of the volatilities of options sharing the same forward and expiry.
+///
+/// The vegas are those of [`vega_ladder`], so the mean is `Σ vegaᵢ σᵢ / Σ vegaᵢ`. As vega does not depend on whether
+/// an option is a call or a put, neither does the mean.
+///
+/// # Arguments
+///
+/// * `forward` - The current value of the underlying asset.
+/// * `expiry` - The time to expiration of the options.
+/// * `strikes` - The strike prices of the options.
+/// * `volatilities` - The volatilities at the corresponding strikes.
+///
+/// # Returns
+///
+/// The vega-weighted mean volatility, or NaN if `strikes` is empty or every vega is zero.
+///
+/// # Examples
+///
+/// ```
+/// let mean = implied_vol::vega_weighted_mean_vol(100.0, 1.0, &[90.0, 110.0], &[0.25, 0.2]);
+/// assert!((mean - 0.22402044956420176).abs() <= 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `strikes` and `volatilities` have different lengths.
+#[inline]
+pub fn vega_weighted_mean_vol(
+    forward: f64,
+    expiry: f64,
+    strikes: &[f64],
+    volatilities: &[f64],
+) -> f64 {
+    greeks::vega_weighted_mean_vol(forward, expiry, strikes, volatilities)
+}
+
+/// Calculates the first and second derivatives of the implied black volatility with respect to the option price.
+///
+/// They follow analytically from the vega and volga at the implied volatility, without inverting the price again.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// `Some((dsigma_dprice, d2sigma_dprice2))`, where `dsigma_dprice = 1 / vega` and
+/// `d2sigma_dprice2 = -volga / vega³`, or `None` if the implied volatility is not finite and positive.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 110.0, 0.2, 1.0, true);
+/// let (dsigma_dprice, _) = implied_vol::implied_black_vol_sensitivities(price, 100.0, 110.0, 1.0, true).unwrap();
+/// let vega = implied_vol::black_greeks(100.0, 110.0, 0.2, 1.0, true).vega;
+/// assert!((dsigma_dprice * vega - 1.0).abs() <= 1e-12);
+/// ```
+#[inline]
+pub fn implied_black_vol_sensitivities(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<(f64, f64)> {
+    greeks::vol_sensitivities(option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates a single implied black volatility from a bid and an ask price.
+///
+/// Both sides are inverted, and their volatilities are averaged with weights given by the vega at each side's own
+/// volatility. A price error moves the volatility by that error over the vega, so the weights favour the side whose
+/// volatility its price pins down more tightly.
+///
+/// # Arguments
+///
+/// * `bid` - The bid price of the option.
+/// * `ask` - The ask price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The vega-weighted mid volatility, or `None` if the implied volatility of either side is not finite and positive.
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes;
+/// let (bid, ask) = (price(100.0, 120.0, 0.2, 1.0, true), price(100.0, 120.0, 0.22, 1.0, true));
+/// let vol = implied_vol::implied_black_vol_weighted_mid(bid, ask, 100.0, 120.0, 1.0, true).unwrap();
+/// assert!(vol > 0.2 && vol < 0.22);
+/// ```
+#[inline]
+pub fn implied_black_vol_weighted_mid(
+    bid: f64,
+    ask: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    greeks::weighted_mid_vol(bid, ask, forward, strike, expiry, is_call)
+}
+
+/// Calculates the delta of a European option in the normalised coordinates of the Black-Scholes model.
+///
+/// With `x = ln(F / K)` and `s = σ√T`, the delta `∂V/∂F` depends on `x` and `s` only, so the normalised delta equals the
+/// delta in raw coordinates.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`, which must be positive.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The delta of the option.
+///
+/// # Examples
+///
+/// ```
+/// let delta = implied_vol::black_normalised_delta(0.0, 0.2, true);
+/// assert!((delta - 0.539827837277029).abs() <= 2.0 * f64::EPSILON);
+/// ```
+#[inline]
+pub fn black_normalised_delta(x: f64, s: f64, is_call: bool) -> f64 {
+    greeks::normalised_delta(x, s, is_call)
+}
+
+/// Calculates the gamma of a European option in the normalised coordinates of the Black-Scholes model.
+///
+/// With `x = ln(F / K)` and `s = σ√T`, the normalised gamma is `F · ∂²V/∂F²`, i.e. the gamma in raw coordinates is
+/// obtained by dividing the result by the forward.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`, which must be positive.
+///
+/// # Returns
+///
+/// The normalised gamma of the option, which is the same for calls and puts.
+///
+/// # Examples
+///
+/// ```
+/// let gamma = implied_vol::black_normalised_gamma(0.0, 0.2);
+/// assert!((gamma - 1.9847627373850587).abs() <= 2.0 * f64::EPSILON * gamma);
+/// ```
+#[inline]
+pub fn black_normalised_gamma(x: f64, s: f64) -> f64 {
+    greeks::normalised_gamma(x, s)
+}
+
+/// Calculates the vega of a European option in the normalised coordinates of the Black-Scholes model.
+///
+/// With `x = ln(F / K)` and `s = σ√T`, the normalised vega is `∂b/∂s` where `b = V / √(FK)` is the normalised price,
+/// i.e. the vega `∂V/∂σ` in raw coordinates is obtained by multiplying the result by `√(FK) · √T`.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`.
+///
+/// # Returns
+///
+/// The normalised vega of the option, which is the same for calls and puts.
+///
+/// # Examples
+///
+/// ```
+/// let vega = implied_vol::black_normalised_vega(0.0, 0.2);
+/// assert!((vega - 0.39695254747701175).abs() <= 2.0 * f64::EPSILON);
+/// ```
+#[inline]
+pub fn black_normalised_vega(x: f64, s: f64) -> f64 {
+    lets_be_rational::normalised_vega(x, s)
+}
+
+/// Calculates the reciprocal of [`black_normalised_vega`], i.e. `∂s/∂b` in the normalised coordinates.
+///
+/// It is computed directly as `√(2π) · exp((x/s)²/2 + s²/8)` rather than as the reciprocal of a vega that may have
+/// underflowed, which is convenient for Newton steps on the normalised price.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`.
+///
+/// # Returns
+///
+/// The inverse normalised vega. It overflows to `f64::INFINITY` for large `(x/s)² + s²/4`, including `s = 0` with
+/// `x ≠ 0`, rather than panicking.
+///
+/// # Examples
+///
+/// ```
+/// let inv_vega = implied_vol::black_normalised_inv_vega(0.5, 0.2);
+/// assert!((inv_vega * implied_vol::black_normalised_vega(0.5, 0.2) - 1.0).abs() <= 8.0 * f64::EPSILON);
+/// assert_eq!(implied_vol::black_normalised_inv_vega(100.0, 1e-3), f64::INFINITY);
+/// ```
+#[inline]
+pub fn black_normalised_inv_vega(x: f64, s: f64) -> f64 {
+    lets_be_rational::inv_normalised_vega(x, s)
+}
+
+/// Calculates the normalised price of a call option scaled by its normalised vega, together with the logarithm of
+/// the normalised vega.
+///
+/// With `x = ln(F / K)` and `s = σ√T`, the normalised price is `b = V / √(FK)` and the normalised vega is `∂b/∂s`.
+/// The result `(b / vega, ln(vega))` gives `ln(b) = ln(b / vega) + ln(vega)` without underflow for deep
+/// out-of-the-money options, whose price and vega are both below the smallest representable `f64`.
+///
+/// The scaling is meant for out-of-the-money calls, i.e. `x <= 0`. For `x > 0` the intrinsic value is divided by the
+/// vega, which overflows to `f64::INFINITY` when the vega underflows.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`.
+///
+/// # Returns
+///
+/// A tuple `(b / vega, ln(vega))` for the call option.
+///
+/// # Examples
+///
+/// ```
+/// let (bx, ln_vega) = implied_vol::black_scaled_normalised_and_ln_vega(-0.5, 0.4);
+/// let price = implied_vol::black_price_from_s(1.0, 0.5_f64.exp(), 0.4, true) / 0.25_f64.exp();
+/// assert!(((bx.ln() + ln_vega).exp() - price).abs() <= 1e-14);
+///
+/// let (bx, ln_vega) = implied_vol::black_scaled_normalised_and_ln_vega(-60.0, 0.5);
+/// assert!((bx.ln() + ln_vega) < f64::MIN_POSITIVE.ln());
+/// ```
+#[inline]
+pub fn black_scaled_normalised_and_ln_vega(x: f64, s: f64) -> (f64, f64) {
+    lets_be_rational::normalised_black_call_over_vega_and_ln_vega(x, s)
+}
+
+/// Calculates the normalised price and the normalised vega of a European option in one pass.
+///
+/// With `x = ln(F / K)` and `s = σ√T`, the normalised price is `b = V / √(FK)` and the normalised vega is `∂b/∂s`.
+/// The vega is computed once and reused by the expansions of the price, and both results are bit-for-bit identical
+/// to [`black_price_from_s`] divided by `√(FK)` and [`black_normalised_vega`].
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// A tuple `(b, vega)` of the normalised price and the normalised vega.
+///
+/// # Examples
+///
+/// ```
+/// let (price, vega) = implied_vol::black_normalised_price_and_vega(0.0, 0.2, true);
+/// assert_eq!(price, implied_vol::black_price_from_s(1.0, 1.0, 0.2, true));
+/// assert_eq!(vega, implied_vol::black_normalised_vega(0.0, 0.2));
+/// ```
+#[inline]
+pub fn black_normalised_price_and_vega(x: f64, s: f64, is_call: bool) -> (f64, f64) {
+    lets_be_rational::normalised_black_and_vega(x, s, is_call)
+}
+
+/// Calculates the intrinsic value of an option in the normalised coordinates of the Black-Scholes model.
+///
+/// With `x = ln(F / K)`, the normalised intrinsic value `(F - K)⁺ / √(FK)` of a call is `2 sinh(x / 2)` for `x > 0`,
+/// and that of a put `2 sinh(-x / 2)` for `x < 0`. It is evaluated by a Taylor series for `|x| < 0.109`, which keeps
+/// full relative accuracy where `exp(x / 2) - exp(-x / 2)` would cancel.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The normalised intrinsic value, which is `0.0` for out-of-the-money options.
+///
+/// # Examples
+///
+/// ```
+/// let intrinsic = implied_vol::black_normalised_intrinsic(1e-10, true);
+/// assert_eq!(intrinsic, 1e-10);
+/// assert_eq!(implied_vol::black_normalised_intrinsic(1e-10, false), 0.0);
+/// let (f, k) = (120.0_f64, 100.0_f64);
+/// let intrinsic = implied_vol::black_normalised_intrinsic((f / k).ln(), true);
+/// assert!((intrinsic - (f - k) / (f * k).sqrt()).abs() <= 1e-15);
+/// ```
+#[inline]
+pub fn black_normalised_intrinsic(x: f64, is_call: bool) -> f64 {
+    lets_be_rational::normalised_intrinsic(x, is_call)
+}
+
+/// Calculates the breakpoints at which the implied volatility solver switches between its initial guesses.
+///
+/// Following "Let's Be Rational" (Jäckel, 2015), the out-of-the-money normalised price `b(x, s)` with `x = -|θx|` is
+/// split at the inflection point `s_c = √(2|x|)` of `s ↦ b(x, s)` and at the two points where the tangent there
+/// reaches the bounds of the price band `[0, b_max)`, where `b_max = exp(x / 2)`:
+///
+/// * `b_c = b(x, s_c)`,
+/// * `b_l = b(x, s_c - b_c / b'(x, s_c))`,
+/// * `b_u = b(x, s_c + (b_max - b_c) / b'(x, s_c))`,
+///
+/// with `b'` the normalised vega. Prices below `b_l` and above `b_u` are inverted through the lower and upper
+/// asymptotic maps, and prices in between by rational cubic interpolation of `s`.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`. Only `|x|` matters.
+///
+/// # Returns
+///
+/// `(b_l, b_c, b_u)`, with `0 <= b_l <= b_c < b_u < b_max`.
+///
+/// # Examples
+///
+/// ```
+/// let (b_l, b_c, b_u) = implied_vol::black_normalised_band(-1.0);
+/// assert!(0.0 < b_l && b_l < b_c && b_c < b_u && b_u < (-0.5_f64).exp());
+/// assert_eq!(b_c, implied_vol::black_normalised_price_and_vega(-1.0, 2.0_f64.sqrt(), true).0);
+/// ```
+#[inline]
+pub fn black_normalised_band(x: f64) -> (f64, f64, f64) {
+    lets_be_rational::normalised_band(x)
+}
+
+/// Returns the method by which the normalised black price is evaluated at a point in the normalised coordinates.
+///
+/// The choice depends on `|x|` only, since in-the-money prices are evaluated as the intrinsic value plus the
+/// out-of-the-money price. It is exposed for studying the accuracy near the boundaries between the methods.
+///
+/// # Arguments
+///
+/// * `x` - The log-moneyness `ln(F / K)`.
+/// * `s` - The total volatility `σ√T`.
+///
+/// # Returns
+///
+/// The [`Region`] containing `(x, s)`.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Region;
+///
+/// assert_eq!(implied_vol::black_expansion_region(-0.1, 1.0), Region::Cody);
+/// assert_eq!(implied_vol::black_expansion_region(-20.0, 1.0), Region::Asymptotic);
+/// assert_eq!(implied_vol::black_expansion_region(-1e-4, 1e-3), Region::SmallT);
+/// ```
+#[inline]
+pub fn black_expansion_region(x: f64, s: f64) -> Region {
+    lets_be_rational::region(x, s)
+}
+
+/// Calculates the implied normal volatility.
+///
+/// Bachelier's model depends on the forward and the strike only through `forward - strike`, so both may be zero or
+/// negative, as for interest rates.
+///
+/// # Arguments
+///
+/// * `price` - The market price of the option.
+/// * `forward` - The forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The implied normal volatility as a `f64` value.
+/// A price below the intrinsic value yields `f64::NEG_INFINITY`, and, if `expiry` is zero, a price above it yields
+/// `f64::INFINITY`; use [`implied_normal_volatility_checked`] to have these reported as errors.
+/// As in [`implied_black_volatility`], the result is `0.0` if `expiry` is infinite and the price is not below the
+/// intrinsic value.
+///
+/// # Examples
+///
+/// ```
+/// let normal_vol = implied_vol::implied_normal_volatility(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(normal_vol, 6.614292466299764);
+///
+/// let price = implied_vol::calculate_european_option_price_by_bachelier(-0.01, -0.02, 0.005, 2.0, true);
+/// let normal_vol = implied_vol::implied_normal_volatility(price, -0.01, -0.02, 2.0, true);
+/// assert!((normal_vol - 0.005).abs() <= 1e-15);
+/// ```
+pub fn implied_normal_volatility(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> f64 {
+    bachelier::implied_normal_volatility(option_price, forward, strike, expiry, is_call)
+}
+
+/// Calculates the implied normal volatility, reporting invalid inputs as errors instead of signal values.
+///
+/// # Arguments
+///
+/// * `option_price` - The current price of the option.
+/// * `forward` - The current forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+///
+/// # Returns
+///
+/// The same implied normal volatility as [`implied_normal_volatility`], or a [`NormalVolError`] if an input is not
+/// finite, the expiry is negative, the price is below the intrinsic value, the expiry is zero and the price above
+/// the intrinsic value, or the volatility overflows. The result is thus never infinite.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::NormalVolError;
+///
+/// let normal_vol = implied_vol::implied_normal_volatility_checked(20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(normal_vol, Ok(6.614292466299764));
+///
+/// let normal_vol = implied_vol::implied_normal_volatility_checked(5.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(normal_vol, Err(NormalVolError::PriceBelowIntrinsic));
+/// ```
+#[inline]
+pub fn implied_normal_volatility_checked(
+    option_price: f64,
+    forward: f64,
+    strike: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Result<f64, NormalVolError> {
+    bachelier::implied_normal_volatility_checked(option_price, forward, strike, expiry, is_call)
+}
 
-/// Calculates the implied black volatility using a transformed rational guess with limited iterations.
+/// Calculates the implied normal volatility from a premium quoted in basis points of the notional, as is common for
+/// rates options.
+///
+/// The premium `price_bps · 10⁻⁴ · notional` is that of `notional` options on a unit notional, so the notional
+/// cancels out of the inversion and only has to be positive.
 ///
 /// # Arguments
 ///
-/// * `option_price` - The current price of the option.
-/// * `forward` - The current forward price of the underlying asset.
-/// * `strike` - The strike price of the option.
+/// * `price_bps` - The premium in basis points of the notional.
+/// * `notional` - The notional of the option, which must be positive and finite.
+/// * `forward` - The current forward rate of the underlying.
+/// * `strike` - The strike rate of the option.
 /// * `expiry` - The time to expiration in years.
 /// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
 ///
 /// # Returns
 ///
-/// The implied black volatility.
+/// The implied normal volatility, or `None` if the notional is not positive and finite or
+/// [`implied_normal_volatility_checked`] rejects the premium.
 ///
 /// # Examples
 ///
 /// ```
-/// let black_vol = implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true);
-/// assert_eq!(black_vol, 0.07011701801482094);
+/// let premium = 1e6 * implied_vol::calculate_european_option_price_by_bachelier(0.03, 0.035, 0.008, 2.0, true);
+/// let price_bps = premium / 1e6 * 1e4;
+/// let normal_vol = implied_vol::implied_normal_volatility_bps(price_bps, 1e6, 0.03, 0.035, 2.0, true).unwrap();
+/// assert!((normal_vol - 0.008).abs() <= 1e-14);
 /// ```
 #[inline]
-pub fn implied_black_volatility(
-    option_price: f64,
+pub fn implied_normal_volatility_bps(
+    price_bps: f64,
+    notional: f64,
     forward: f64,
     strike: f64,
     expiry: f64,
     is_call: bool,
-) -> f64 {
-    lets_be_rational::implied_black_volatility(option_price, forward, strike, expiry, is_call)
+) -> Option<f64> {
+    bachelier::implied_normal_volatility_bps(price_bps, notional, forward, strike, expiry, is_call)
 }
 
-/// Calculates the price of a European option using the Black-Scholes formula.
+/// Calculates the implied normal volatility from normalised inputs.
 ///
 /// # Arguments
 ///
-/// * `forward` - The current value of the underlying asset.
-/// * `strike` - The strike price of the option.
-/// * `volatility` - The volatility of the underlying asset.
-/// * `expiry` - The time to expiration of the option.
-/// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+/// * `option_price` - The market price of the option.
+/// * `intrinsic_price` - The intrinsic value of the option.
+/// * `absolute_moneyness` - The absolute difference between the forward and the strike, `|F - K|`.
+/// * `expiry` - The time to expiration in years.
 ///
 /// # Returns
 ///
-/// The price of the European option.
+/// The implied normal volatility as a `f64` value.
+/// As for [`implied_normal_volatility`], a price below `intrinsic_price` yields `f64::NEG_INFINITY`, and, if
+/// `expiry` is zero, a price above it yields `f64::INFINITY`.
 ///
 /// # Examples
 ///
 /// ```
-/// let price = implied_vol::calculate_european_option_price_by_black_scholes(100.0, 90.0, 0.07011701801482094, 30.0, true);
-/// assert!((price - 20.0).abs()<= 2.0 * f64::EPSILON * 20.0);
+/// let (price, intrinsic, absolute_moneyness) = implied_vol::bachelier_normalise_inputs(20.0, 100.0, 90.0, true);
+/// let normal_vol = implied_vol::implied_normal_volatility_normalised(price, intrinsic, absolute_moneyness, 30.0);
+/// assert_eq!(normal_vol, implied_vol::implied_normal_volatility(20.0, 100.0, 90.0, 30.0, true));
 /// ```
 #[inline]
-pub fn calculate_european_option_price_by_black_scholes(
-    forward: f64,
-    strike: f64,
-    volatility: f64,
+pub fn implied_normal_volatility_normalised(
+    option_price: f64,
+    intrinsic_price: f64,
+    absolute_moneyness: f64,
     expiry: f64,
-    is_call: bool,
 ) -> f64 {
-    lets_be_rational::black(forward, strike, volatility, expiry, is_call)
+    bachelier::implied_normal_volatility_normalised(
+        option_price,
+        intrinsic_price,
+        absolute_moneyness,
+        expiry,
+    )
 }
 
-/// Calculates the implied normal volatility.
+/// Maps raw Bachelier inputs to the arguments of [`implied_normal_volatility_normalised`].
 ///
 /// # Arguments
 ///
-/// * `price` - The market price of the option.
+/// * `option_price` - The market price of the option.
 /// * `forward` - The forward price of the underlying asset.
 /// * `strike` - The strike price of the option.
-/// * `expiry` - The time to expiration in years.
 /// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
 ///
 /// # Returns
 ///
-/// The implied normal volatility as a `f64` value.
+/// A tuple `(option_price, intrinsic_price, absolute_moneyness)` where `absolute_moneyness` is `|F - K|`.
 ///
 /// # Examples
 ///
 /// ```
-/// let normal_vol = implied_vol::implied_normal_volatility(20.0, 100.0, 90.0, 30.0, true);
-/// assert_eq!(normal_vol, 6.614292466299764);
+/// let (price, intrinsic, absolute_moneyness) = implied_vol::bachelier_normalise_inputs(20.0, 100.0, 90.0, true);
+/// assert_eq!((price, intrinsic, absolute_moneyness), (20.0, 10.0, 10.0));
 /// ```
-pub fn implied_normal_volatility(
+#[inline]
+pub fn bachelier_normalise_inputs(
     option_price: f64,
     forward: f64,
     strike: f64,
-    expiry: f64,
     is_call: bool,
-) -> f64 {
-    bachelier::implied_normal_volatility(option_price, forward, strike, expiry, is_call)
+) -> (f64, f64, f64) {
+    bachelier::normalise_inputs(option_price, forward, strike, is_call)
+}
+
+/// Calculates `Φ̃(x) = Φ(x) + φ(x) / x`, the normalised time value function of Bachelier's model.
+///
+/// The time value of a Bachelier option with `F ≠ K` equals `-|F - K| · Φ̃(x)` with `x = -|F - K| / (σ√T)`.
+/// See Jäckel's note
+/// [Implied Normal Volatility](http://www.jaeckel.org/ImpliedNormalVolatility.pdf).
+///
+/// # Arguments
+///
+/// * `x` - A non-zero real number.
+///
+/// # Returns
+///
+/// `Φ̃(x)`, which is negative for `x < 0` and greater than `1` for `x > 0`.
+///
+/// # Examples
+///
+/// ```
+/// let y = implied_vol::bachelier_phi_tilde(-1.0);
+/// assert!((y - -0.0833154705876863).abs() <= 4.0 * f64::EPSILON * y.abs());
+/// ```
+#[inline]
+pub fn bachelier_phi_tilde(x: f64) -> f64 {
+    bachelier::phi_tilde(x)
+}
+
+/// Calculates the inverse of [`bachelier_phi_tilde`].
+///
+/// This is the core of [`implied_normal_volatility`]: for a price above the intrinsic value and `F ≠ K`, the normal
+/// volatility is `|F - K| / (|x|√T)` with `x = bachelier_inv_phi_tilde((intrinsic - price) / |F - K|)`, where the
+/// argument is negative.
+///
+/// # Arguments
+///
+/// * `y` - A value of `Φ̃`. The main branch takes `y < 0` and yields `x < 0`; `y > 1` yields `x > 0` by the symmetry
+///   `Φ̃(-x) = 1 - Φ̃(x)`, which loses precision as `y` approaches `1`.
+///
+/// # Returns
+///
+/// The `x` such that `Φ̃(x) = y`, or NaN if `y` is within `[0, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// let x = implied_vol::bachelier_inv_phi_tilde(implied_vol::bachelier_phi_tilde(-1.0));
+/// assert!((x - -1.0).abs() <= 1e-14);
+/// assert!(implied_vol::bachelier_inv_phi_tilde(0.5).is_nan());
+///
+/// let (forward, strike, expiry) = (100.0, 90.0, 30.0_f64);
+/// let x = implied_vol::bachelier_inv_phi_tilde((10.0 - 20.0) / (forward - strike));
+/// let normal_vol = (forward - strike) / (x * expiry.sqrt()).abs();
+/// assert_eq!(normal_vol, implied_vol::implied_normal_volatility(20.0, forward, strike, expiry, true));
+/// ```
+#[inline]
+pub fn bachelier_inv_phi_tilde(y: f64) -> f64 {
+    bachelier::inv_phi_tilde(y)
 }
 
 /// Calculates the price of an option using Bachelier's model.
 ///
+/// The forward and the strike may be zero or negative.
+///
 /// # Arguments
 ///
 /// * `forward` - The forward price of the underlying asset.
@@ -179,6 +2675,92 @@ pub fn calculate_european_option_price_by_bachelier(
     bachelier::bachelier(forward, strike, volatility, expiry, is_call)
 }
 
+/// Calculates the price of an option using Bachelier's model, rejecting invalid inputs instead of pricing them.
+///
+/// # Arguments
+///
+/// * `forward` - The forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration in years.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or a put (false).
+///
+/// # Returns
+///
+/// `None` if `forward` or `strike` is NaN, or if `volatility` or `expiry` is NaN or negative.
+/// Otherwise, the same price as [`calculate_european_option_price_by_bachelier`].
+///
+/// # Examples
+///
+/// ```
+/// let price = implied_vol::calculate_european_option_price_by_bachelier_checked(100.0, 90.0, 6.614292466299764, 30.0, true);
+/// assert_eq!(price, Some(implied_vol::calculate_european_option_price_by_bachelier(100.0, 90.0, 6.614292466299764, 30.0, true)));
+///
+/// let price = implied_vol::calculate_european_option_price_by_bachelier_checked(f64::NAN, 90.0, 6.614292466299764, 30.0, true);
+/// assert_eq!(price, None);
+/// ```
+#[inline]
+pub fn calculate_european_option_price_by_bachelier_checked(
+    forward: f64,
+    strike: f64,
+    volatility: f64,
+    expiry: f64,
+    is_call: bool,
+) -> Option<f64> {
+    bachelier::bachelier_checked(forward, strike, volatility, expiry, is_call)
+}
+
+/// Returns the no-arbitrage bounds of an option price in Bachelier's model.
+///
+/// The normal model has no upper bound on the price, so only the intrinsic value constrains it.
+///
+/// # Arguments
+///
+/// * `forward` - The forward price of the underlying asset.
+/// * `strike` - The strike price of the option.
+/// * `is_call` - A boolean flag indicating whether the option is a call (true) or a put (false).
+///
+/// # Returns
+///
+/// `(intrinsic, f64::INFINITY)`, where `intrinsic` is `max(forward - strike, 0)` for calls and
+/// `max(strike - forward, 0)` for puts.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(implied_vol::bachelier_price_bounds(0.03, 0.01, true), (0.03 - 0.01, f64::INFINITY));
+/// assert_eq!(implied_vol::bachelier_price_bounds(0.03, 0.01, false), (0.0, f64::INFINITY));
+/// ```
+#[inline]
+pub fn bachelier_price_bounds(forward: f64, strike: f64, is_call: bool) -> (f64, f64) {
+    bachelier::price_bounds(forward, strike, is_call)
+}
+
+/// Calculates the price of an at-the-money option in Bachelier's model, `σ√T / √(2π)`.
+///
+/// Calls and puts have the same price at the money.
+///
+/// # Arguments
+///
+/// * `volatility` - The volatility of the underlying asset.
+/// * `expiry` - The time to expiration in years.
+///
+/// # Returns
+///
+/// The price of the option with `strike == forward`.
+///
+/// # Examples
+///
+/// ```
+/// let atm = implied_vol::bachelier_atm_price(0.01, 2.0);
+/// let price = implied_vol::calculate_european_option_price_by_bachelier(0.02, 0.02, 0.01, 2.0, true);
+/// assert!((atm - price).abs() <= 2.0 * f64::EPSILON * atm);
+/// ```
+#[inline]
+pub fn bachelier_atm_price(volatility: f64, expiry: f64) -> f64 {
+    bachelier::atm_price(volatility, expiry)
+}
+
 #[cfg(feature = "error-function")]
 /// Calculates the scaled complementary error function of `x`.
 ///
@@ -204,6 +2786,63 @@ pub fn erfcx(x: f64) -> f64 {
     erf_cody::erfcx_cody(x)
 }
 
+#[cfg(feature = "error-function")]
+/// Calculates the scaled complementary error function of `x` with a chosen treatment of overflow.
+///
+/// `erfcx(x)` exceeds `f64::MAX` for `x < -26.628`. [`erfcx`] saturates to `f64::MAX` there, which is also what
+/// [`ErfcxPolicy::Saturate`] does.
+///
+/// # Arguments
+///
+/// * `x` - The input value to calculate the scaled complementary error function for.
+/// * `policy` - The treatment of arguments for which the result overflows.
+///
+/// # Returns
+///
+/// The same result as [`erfcx`] for `x >= -26.628`. Below that, `f64::MAX`, `f64::INFINITY`, or an
+/// [`ErfcxOverflow`] error, depending on `policy`.
+///
+/// # Example
+///
+/// ```
+/// use implied_vol::{erfcx_with_policy, ErfcxPolicy};
+///
+/// assert_eq!(erfcx_with_policy(0.5, ErfcxPolicy::Error), Ok(implied_vol::erfcx(0.5)));
+/// assert_eq!(erfcx_with_policy(-30.0, ErfcxPolicy::Infinity), Ok(f64::INFINITY));
+/// assert!(erfcx_with_policy(-30.0, ErfcxPolicy::Error).is_err());
+/// ```
+#[inline]
+pub fn erfcx_with_policy(x: f64, policy: ErfcxPolicy) -> Result<f64, ErfcxOverflow> {
+    erf_cody::erfcx_with_policy(x, policy)
+}
+
+#[cfg(feature = "error-function")]
+/// Calculates `1 - erfcx(x)` without the cancellation of the naive difference for small `|x|`.
+///
+/// For `|x| < 0.25` the result is evaluated from the power series
+/// `1 - erfcx(x) = 2x/√π - x² + 4x³/(3√π) - ...`, so it keeps full relative accuracy (a few ulps) as `x` approaches zero,
+/// where `1.0 - erfcx(x)` loses all significant digits. Elsewhere the naive difference is used, which is accurate there.
+/// The function is defined for all real `x`.
+///
+/// # Arguments
+///
+/// * `x` - The input value.
+///
+/// # Returns
+///
+/// The value of `1 - exp(x^2) * erfc(x)`.
+///
+/// # Example
+///
+/// ```
+/// let result = implied_vol::one_minus_erfcx(1e-8);
+/// assert!(((result - 1.1283791570955126e-8) / result).abs() <= f64::EPSILON);
+/// ```
+#[inline]
+pub fn one_minus_erfcx(x: f64) -> f64 {
+    erf_cody::one_minus_erfcx(x)
+}
+
 #[cfg(feature = "error-function")]
 /// Calculates the complementary error function.
 ///
@@ -226,6 +2865,82 @@ pub fn erfc(x: f64) -> f64 {
     erf_cody::erfc_cody(x)
 }
 
+#[cfg(feature = "error-function")]
+/// Calculates the error function.
+///
+/// # Arguments
+///
+/// * `x` - The input number for which the error function needs to be calculated.
+///
+/// # Returns
+///
+/// The result of the error function calculation.
+///
+/// # Example
+///
+/// ```
+/// let result = implied_vol::erf(0.5);
+/// assert!((result - 0.5204998778130465) / result <= f64::EPSILON);
+/// ```
+#[inline]
+pub fn erf(x: f64) -> f64 {
+    erf_cody::erf_cody(x)
+}
+
+#[cfg(feature = "error-function")]
+/// Calculates the error function for each element of `input`, writing the results into `out`.
+///
+/// The loop is monomorphic so that the compiler is free to vectorize the evaluation.
+///
+/// # Arguments
+///
+/// * `input` - The input numbers.
+/// * `out` - The buffer receiving `erf(input[i])` at index `i`.
+///
+/// # Example
+///
+/// ```
+/// let input = [0.5, -0.5];
+/// let mut out = [0.0; 2];
+/// implied_vol::erf_slice(&input, &mut out);
+/// assert_eq!(out, [implied_vol::erf(0.5), implied_vol::erf(-0.5)]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `input` and `out` have different lengths.
+#[inline]
+pub fn erf_slice(input: &[f64], out: &mut [f64]) {
+    erf_cody::erf_slice(input, out);
+}
+
+#[cfg(feature = "error-function")]
+/// Calculates the complementary error function for each element of `input`, writing the results into `out`.
+///
+/// The loop is monomorphic so that the compiler is free to vectorize the evaluation.
+///
+/// # Arguments
+///
+/// * `input` - The input numbers.
+/// * `out` - The buffer receiving `erfc(input[i])` at index `i`.
+///
+/// # Example
+///
+/// ```
+/// let input = [0.5, -0.5];
+/// let mut out = [0.0; 2];
+/// implied_vol::erfc_slice(&input, &mut out);
+/// assert_eq!(out, [implied_vol::erfc(0.5), implied_vol::erfc(-0.5)]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `input` and `out` have different lengths.
+#[inline]
+pub fn erfc_slice(input: &[f64], out: &mut [f64]) {
+    erf_cody::erfc_slice(input, out);
+}
+
 /// Calculates the probability density function of a standard normal distribution.
 ///
 /// # Arguments
@@ -269,6 +2984,33 @@ pub fn norm_cdf(x: f64) -> f64 {
     normal_distribution::norm_cdf(x)
 }
 
+/// Calculates the lower tail of the cumulative distribution function (CDF) of the standard normal distribution by
+/// its asymptotic expansion.
+///
+/// Far in the lower tail, `0.5 * erfc(-x / √2)` loses relative accuracy, by about 150 ulps at `x = -30`, while the
+/// expansion stays accurate to full relative precision for `x <= -10`. Above that, the expansion does not converge
+/// to full precision, so use [`norm_cdf`], which switches to it automatically for `x <= -10`.
+///
+/// # Arguments
+///
+/// * `x` - The value at which to calculate the CDF, at most `-10`.
+///
+/// # Returns
+///
+/// The CDF value for `x` in the standard normal distribution.
+///
+/// # Examples
+///
+/// ```
+/// let cdf = implied_vol::norm_cdf_tail(-30.0);
+/// assert!((cdf - 4.906713927148187e-198).abs() <= 2.0 * f64::EPSILON * cdf);
+/// ```
+#[cfg(feature = "normal-distribution")]
+#[inline]
+pub fn norm_cdf_tail(x: f64) -> f64 {
+    normal_distribution::norm_cdf_tail(x)
+}
+
 #[cfg(feature = "normal-distribution")]
 /// Calculates the inverse cumulative distribution function (CDF).
 ///
@@ -279,6 +3021,18 @@ pub fn norm_cdf(x: f64) -> f64 {
 ///
 /// * `x` - The probability value between 0 and 1.
 ///
+/// # Accuracy
+///
+/// The implementation is Wichura's algorithm AS241, which uses a separate rational approximation on each of three
+/// regions and has a relative error of about `1e-16` on each:
+///
+/// * `|x - 0.5| <= 0.425`, i.e. results up to about `1.44` in magnitude;
+/// * `min(x, 1 - x) >= exp(-25)`, i.e. about `1.4e-11`, for results up to about `6.7` in magnitude;
+/// * smaller tail probabilities, down to the smallest subnormal `5e-324`, where the result is about `-38.47`.
+///
+/// The regression tests pin the results at probabilities from `5e-324` through `1e-200` and `1e-16` to `1 - 1e-10`
+/// to within two units in the last place of high-precision references.
+///
 /// # Examples
 ///
 /// ```
@@ -294,3 +3048,161 @@ pub fn norm_cdf(x: f64) -> f64 {
 pub fn inverse_norm_cdf(x: f64) -> f64 {
     normal_distribution::inverse_norm_cdf(x)
 }
+
+#[cfg(feature = "normal-distribution")]
+/// Calculates Owen's T function.
+///
+/// Owen's T function is defined as `T(h, a) = 1/(2π) ∫_0^a exp(-h²(1+x²)/2) / (1+x²) dx` and is a building block of
+/// the bivariate normal distribution. The result is accurate to about `1e-15` in absolute terms.
+///
+/// # Arguments
+///
+/// * `h` - The first argument.
+/// * `a` - The second argument.
+///
+/// # Examples
+///
+/// ```
+/// let t = implied_vol::owens_t(0.0, 1.0);
+/// assert!((t - 0.125).abs() <= f64::EPSILON);
+/// ```
+#[inline]
+pub fn owens_t(h: f64, a: f64) -> f64 {
+    normal_distribution::owens_t(h, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden_bit_patterns() {
+        let cases = [
+            (
+                (20.0, 100.0, 90.0, 30.0, true),
+                [
+                    0x3fb1_f330_5b44_465f_u64,
+                    0x404e_8219_7513_7f45,
+                    0x401a_7509_1593_bf26,
+                    0x4024_0000_0001_479e,
+                ],
+            ),
+            (
+                (0.5, 1.0, 1.5, 0.25, true),
+                [
+                    0x400a_22df_6a7f_be7d,
+                    0x3f29_3581_34b1_cc68,
+                    0x400c_fb7d_1745_f866,
+                    0x3ef1_a0da_0c55_537f,
+                ],
+            ),
+            (
+                (0.2, 1.0, 0.8, 2.0, false),
+                [
+                    0x3fe2_d213_ac1c_ce76,
+                    0x3fb2_3c38_e838_83be,
+                    0x3fe0_6518_b99b_f86a,
+                    0x3fb6_74fb_a052_ce7a,
+                ],
+            ),
+            (
+                (3.0, 100.0, 100.0, 1.0, false),
+                [
+                    0x3fb3_4164_bb20_9750,
+                    0x4027_d8da_08ed_45ab,
+                    0x401e_145c_aff1_3a89,
+                    0x3fbe_a386_3e31_dac7,
+                ],
+            ),
+        ];
+        for ((price, f, k, t, is_call), expected) in cases {
+            let actual = [
+                implied_black_volatility(price, f, k, t, is_call),
+                calculate_european_option_price_by_black_scholes(f, k, 0.3, t, is_call),
+                implied_normal_volatility(price, f, k, t, is_call),
+                calculate_european_option_price_by_bachelier(f, k, 0.3, t, is_call),
+            ];
+            assert_eq!(actual.map(f64::to_bits), expected);
+        }
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn black_price_matches_runtime_flag() {
+        for forward in [0.5, 1.0, 100.0] {
+            for strike in [0.25, 1.0, 90.0, 110.0] {
+                for volatility in [0.0, 0.01, 0.2, 1.5] {
+                    for expiry in [0.0, 0.1, 1.0, 30.0] {
+                        assert_eq!(
+                            black_price::<true>(forward, strike, volatility, expiry).to_bits(),
+                            calculate_european_option_price_by_black_scholes(
+                                forward, strike, volatility, expiry, true
+                            )
+                            .to_bits()
+                        );
+                        assert_eq!(
+                            black_price::<false>(forward, strike, volatility, expiry).to_bits(),
+                            calculate_european_option_price_by_black_scholes(
+                                forward, strike, volatility, expiry, false
+                            )
+                            .to_bits()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn implied_black_volatility_const_matches_runtime_flag() {
+        for forward in [0.5, 1.0, 100.0] {
+            for strike in [0.25, 1.0, 90.0, 110.0] {
+                for expiry in [0.0, 0.1, 1.0, 30.0] {
+                    for fraction in [0.0, 0.1, 0.5, 0.99, 1.0] {
+                        let price = fraction * forward;
+                        assert_eq!(
+                            implied_black_volatility_const::<true>(price, forward, strike, expiry)
+                                .to_bits(),
+                            implied_black_volatility(price, forward, strike, expiry, true)
+                                .to_bits()
+                        );
+                        let price = fraction * strike;
+                        assert_eq!(
+                            implied_black_volatility_const::<false>(price, forward, strike, expiry)
+                                .to_bits(),
+                            implied_black_volatility(price, forward, strike, expiry, false)
+                                .to_bits()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn public_types_are_send_and_sync() {
+        assert_send_sync::<AccuracyReport>();
+        assert_send_sync::<BachelierPricer>();
+        assert_send_sync::<BlackScholesGreeks>();
+        assert_send_sync::<BlackScholesPricer>();
+        assert_send_sync::<BlackSolver>();
+        assert_send_sync::<Convergence>();
+        assert_send_sync::<DayCount>();
+        assert_send_sync::<Diagnostics>();
+        assert_send_sync::<ImpliedVolError>();
+        assert_send_sync::<Model>();
+        assert_send_sync::<NormalVolError>();
+        assert_send_sync::<OptionKind>();
+        assert_send_sync::<QuoteDiagnostics>();
+        assert_send_sync::<Region>();
+        assert_send_sync::<RoundTrip>();
+        assert_send_sync::<VolSurface>();
+        assert_send_sync::<Box<dyn Pricer + Send + Sync>>();
+        #[cfg(feature = "error-function")]
+        {
+            assert_send_sync::<ErfcxOverflow>();
+            assert_send_sync::<ErfcxPolicy>();
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::bachelier::implied_normal_volatility_checked;
+use crate::lets_be_rational::implied_black_volatility_checked;
+
+/// The pricing model whose volatility [`crate::implied_volatility`] solves for.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::Model;
+///
+/// let black_vol = implied_vol::implied_volatility(Model::Black, 20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(black_vol, Some(implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true)));
+/// let normal_vol = implied_vol::implied_volatility(Model::Bachelier, 20.0, 100.0, 90.0, 30.0, true);
+/// assert_eq!(normal_vol, Some(implied_vol::implied_normal_volatility(20.0, 100.0, 90.0, 30.0, true)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Model {
+    /// The Black model, whose volatility is lognormal, i.e. relative to the level of the forward.
+    Black,
+    /// Bachelier's model, whose volatility is normal, i.e. in the units of the forward.
+    Bachelier,
+}
+
+pub(crate) fn implied_volatility(
+    model: Model,
+    price: f64,
+    forward: f64,
+    strike: f64,
+    t: f64,
+    q: bool,
+) -> Option<f64> {
+    match model {
+        Model::Black => implied_black_volatility_checked(price, forward, strike, t, q).ok(),
+        Model::Bachelier => implied_normal_volatility_checked(price, forward, strike, t, q).ok(),
+    }
+}
+
+pub(crate) fn implied_volatility_adaptive(
+    price: f64,
+    forward: f64,
+    strike: f64,
+    t: f64,
+    q: bool,
+    forward_floor: f64,
+) -> Option<(Model, f64)> {
+    let model = if forward < forward_floor || strike < forward_floor {
+        Model::Bachelier
+    } else {
+        Model::Black
+    };
+    Some((
+        model,
+        implied_volatility(model, price, forward, strike, t, q)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bachelier::{bachelier, implied_normal_volatility};
+    use crate::lets_be_rational::{black, implied_black_volatility};
+
+    #[test]
+    fn dispatches_to_dedicated_solvers() {
+        for (k, q) in [(80.0, true), (100.0, false), (120.0, true), (90.0, false)] {
+            let price = black(100.0, k, 0.25, 2.0, q);
+            assert_eq!(
+                implied_volatility(Model::Black, price, 100.0, k, 2.0, q),
+                Some(implied_black_volatility(price, 100.0, k, 2.0, q))
+            );
+            let price = bachelier(100.0, k, 25.0, 2.0, q);
+            assert_eq!(
+                implied_volatility(Model::Bachelier, price, 100.0, k, 2.0, q),
+                Some(implied_normal_volatility(price, 100.0, k, 2.0, q))
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        for model in [Model::Black, Model::Bachelier] {
+            assert_eq!(implied_volatility(model, 5.0, 100.0, 90.0, 1.0, true), None);
+            assert_eq!(
+                implied_volatility(model, f64::NAN, 100.0, 90.0, 1.0, true),
+                None
+            );
+        }
+        assert_eq!(
+            implied_volatility(Model::Black, 150.0, 100.0, 90.0, 1.0, true),
+            None
+        );
+        assert!(implied_volatility(Model::Bachelier, 150.0, 100.0, 90.0, 1.0, true).is_some());
+    }
+
+    #[test]
+    fn adaptive_switches_at_the_floor() {
+        let floor = 0.01;
+        for (forward, strike) in [(0.03, 0.02), (0.01, 0.01), (0.02, 0.01)] {
+            for q in [true, false] {
+                let price = black(forward, strike, 0.4, 1.0, q);
+                let (model, vol) =
+                    implied_volatility_adaptive(price, forward, strike, 1.0, q, floor).unwrap();
+                assert_eq!(model, Model::Black);
+                assert!((vol - 0.4).abs() <= 1e-13);
+            }
+        }
+        for (forward, strike) in [(0.005, 0.02), (0.02, 0.005), (-0.01, -0.02)] {
+            for q in [true, false] {
+                let price = bachelier(forward, strike, 0.008, 1.0, q);
+                let (model, vol) =
+                    implied_volatility_adaptive(price, forward, strike, 1.0, q, floor).unwrap();
+                assert_eq!(model, Model::Bachelier);
+                assert!((vol - 0.008).abs() <= 1e-15);
+            }
+        }
+        assert_eq!(
+            implied_volatility_adaptive(0.5, 0.03, 0.02, 1.0, true, floor),
+            None
+        );
+    }
+}
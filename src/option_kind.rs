@@ -0,0 +1,72 @@
+/// The kind of a European option, a readable alternative to the `is_call` flags taken by the functions of this crate.
+///
+/// It converts to and from the `is_call` flag, with `true` meaning [`OptionKind::Call`].
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::OptionKind;
+///
+/// let black_vol = implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, OptionKind::Call.into());
+/// assert_eq!(black_vol, implied_vol::implied_black_volatility(20.0, 100.0, 90.0, 30.0, true));
+/// assert_eq!(OptionKind::from(false), OptionKind::Put);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    /// A call option.
+    Call,
+    /// A put option.
+    Put,
+}
+
+impl OptionKind {
+    /// Returns `true` for [`OptionKind::Call`] and `false` for [`OptionKind::Put`].
+    #[inline]
+    pub const fn is_call(self) -> bool {
+        matches!(self, OptionKind::Call)
+    }
+}
+
+impl From<bool> for OptionKind {
+    #[inline]
+    fn from(is_call: bool) -> Self {
+        if is_call {
+            OptionKind::Call
+        } else {
+            OptionKind::Put
+        }
+    }
+}
+
+impl From<OptionKind> for bool {
+    #[inline]
+    fn from(kind: OptionKind) -> Self {
+        kind.is_call()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lets_be_rational::{black, implied_black_volatility};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(OptionKind::from(true), OptionKind::Call);
+        assert_eq!(OptionKind::from(false), OptionKind::Put);
+        assert!(bool::from(OptionKind::Call));
+        assert!(!bool::from(OptionKind::Put));
+    }
+
+    #[test]
+    fn same_results_as_flags() {
+        for (kind, is_call) in [(OptionKind::Call, true), (OptionKind::Put, false)] {
+            let price = black(100.0, 90.0, 0.2, 1.0, kind.into());
+            assert_eq!(price, black(100.0, 90.0, 0.2, 1.0, is_call));
+            assert_eq!(
+                implied_black_volatility(price, 100.0, 90.0, 1.0, kind.into()),
+                implied_black_volatility(price, 100.0, 90.0, 1.0, is_call)
+            );
+        }
+    }
+}
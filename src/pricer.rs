@@ -0,0 +1,187 @@
+use crate::bachelier::bachelier;
+use crate::lets_be_rational::{black, implied_black_volatility_checked};
+
+/// An object-safe interface to option pricers, so that different models can be held as `Box<dyn Pricer>`.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::{BachelierPricer, BlackScholesPricer, Pricer};
+///
+/// let pricers: Vec<Box<dyn Pricer>> = vec![
+///     Box::new(BlackScholesPricer { forward: 100.0, strike: 90.0, volatility: 0.2, expiry: 1.0, is_call: true }),
+///     Box::new(BachelierPricer { forward: 100.0, strike: 90.0, volatility: 20.0, expiry: 1.0, is_call: true }),
+/// ];
+/// assert!(pricers.iter().all(|pricer| pricer.price() > 10.0));
+/// ```
+pub trait Pricer {
+    /// Calculates the price of the option.
+    fn price(&self) -> f64;
+}
+
+/// A European option priced by the Black-Scholes model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackScholesPricer {
+    /// The current forward price of the underlying asset.
+    pub forward: f64,
+    /// The strike price of the option.
+    pub strike: f64,
+    /// The volatility of the underlying asset.
+    pub volatility: f64,
+    /// The time to expiration in years.
+    pub expiry: f64,
+    /// Whether the option is a call (true) or put (false).
+    pub is_call: bool,
+}
+
+impl BlackScholesPricer {
+    /// Creates a pricer at the implied black volatility of an option price, e.g. to compute greeks or stressed prices
+    /// at the solved volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_price` - The current price of the option.
+    /// * `forward` - The current forward price of the underlying asset.
+    /// * `strike` - The strike price of the option.
+    /// * `expiry` - The time to expiration in years.
+    /// * `is_call` - A boolean flag indicating whether the option is a call (true) or put (false).
+    ///
+    /// # Returns
+    ///
+    /// The pricer, or `None` if [`crate::implied_black_volatility_checked`] rejects the inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use implied_vol::{BlackScholesPricer, Pricer};
+    ///
+    /// let pricer = BlackScholesPricer::from_implied(20.0, 100.0, 90.0, 30.0, true).unwrap();
+    /// assert_eq!(pricer.volatility, 0.07011701801482094);
+    /// assert!((pricer.price() - 20.0).abs() <= 1e-13);
+    /// assert_eq!(BlackScholesPricer::from_implied(5.0, 100.0, 90.0, 30.0, true), None);
+    /// ```
+    pub fn from_implied(
+        option_price: f64,
+        forward: f64,
+        strike: f64,
+        expiry: f64,
+        is_call: bool,
+    ) -> Option<Self> {
+        let volatility =
+            implied_black_volatility_checked(option_price, forward, strike, expiry, is_call)
+                .ok()?;
+        Some(Self {
+            forward,
+            strike,
+            volatility,
+            expiry,
+            is_call,
+        })
+    }
+}
+
+impl Pricer for BlackScholesPricer {
+    #[inline]
+    fn price(&self) -> f64 {
+        black(
+            self.forward,
+            self.strike,
+            self.volatility,
+            self.expiry,
+            self.is_call,
+        )
+    }
+}
+
+/// A European option priced by Bachelier's model.
+///
+/// The forward and the strike may be zero or negative, as for interest rates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BachelierPricer {
+    /// The current forward price of the underlying asset.
+    pub forward: f64,
+    /// The strike price of the option.
+    pub strike: f64,
+    /// The normal volatility of the underlying asset.
+    pub volatility: f64,
+    /// The time to expiration in years.
+    pub expiry: f64,
+    /// Whether the option is a call (true) or put (false).
+    pub is_call: bool,
+}
+
+impl Pricer for BachelierPricer {
+    #[inline]
+    fn price(&self) -> f64 {
+        bachelier(
+            self.forward,
+            self.strike,
+            self.volatility,
+            self.expiry,
+            self.is_call,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heterogeneous_pricers() {
+        let pricers: Vec<Box<dyn Pricer>> = vec![
+            Box::new(BlackScholesPricer {
+                forward: 100.0,
+                strike: 90.0,
+                volatility: 0.2,
+                expiry: 1.0,
+                is_call: true,
+            }),
+            Box::new(BachelierPricer {
+                forward: 100.0,
+                strike: 110.0,
+                volatility: 20.0,
+                expiry: 2.0,
+                is_call: false,
+            }),
+        ];
+        let prices: Vec<f64> = pricers.iter().map(|pricer| pricer.price()).collect();
+        assert_eq!(
+            prices,
+            [
+                black(100.0, 90.0, 0.2, 1.0, true),
+                bachelier(100.0, 110.0, 20.0, 2.0, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn from_implied_reprices() {
+        for (forward, strike, expiry) in
+            [(100.0, 90.0, 1.0), (100.0, 130.0, 0.25), (1.0, 1.0, 10.0)]
+        {
+            for is_call in [true, false] {
+                let option_price = black(forward, strike, 0.3, expiry, is_call);
+                let pricer = BlackScholesPricer::from_implied(
+                    option_price,
+                    forward,
+                    strike,
+                    expiry,
+                    is_call,
+                )
+                .unwrap();
+                assert_eq!((pricer.forward, pricer.strike), (forward, strike));
+                assert_eq!((pricer.expiry, pricer.is_call), (expiry, is_call));
+                assert!((pricer.price() - option_price).abs() <= 1e-14 * forward);
+            }
+        }
+        assert_eq!(
+            BlackScholesPricer::from_implied(f64::NAN, 100.0, 90.0, 1.0, true),
+            None
+        );
+        assert_eq!(
+            BlackScholesPricer::from_implied(10.0, 100.0, 90.0, -1.0, true),
+            None
+        );
+    }
+}
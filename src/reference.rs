@@ -0,0 +1,63 @@
+//! A slow but straightforward black pricer, used as an oracle independent of the expansions in
+//! [`crate::lets_be_rational`].
+
+use crate::erf_cody::erfc_cody;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// Prices an option as `F Φ(θd₁) - K Φ(θd₂)`, up to the sign `θ`, with `Φ(z) = erfc(-z/√2) / 2`.
+///
+/// Its absolute error is a few ulps of the larger of the two terms, amplified by `d₁²` through the conditioning of `Φ`
+/// in the tails. It thus loses relative accuracy where the terms cancel, i.e. for deep out-of-the-money options and for
+/// small total volatilities.
+pub(crate) fn black_reference(f: f64, k: f64, sigma: f64, t: f64, is_call: bool) -> f64 {
+    let (forward_leg, strike_leg) = legs(f, k, sigma, t, is_call);
+    forward_leg - strike_leg
+}
+
+fn legs(f: f64, k: f64, sigma: f64, t: f64, is_call: bool) -> (f64, f64) {
+    let s = sigma * t.sqrt();
+    let d1 = (f / k).ln() / s + 0.5 * s;
+    let d2 = d1 - s;
+    let theta = if is_call { 1.0 } else { -1.0 };
+    let cdf = |z: f64| 0.5 * erfc_cody(-z * FRAC_1_SQRT_2);
+    (theta * f * cdf(theta * d1), theta * k * cdf(theta * d2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::SIXTEENTH_ROOT_DBL_EPSILON;
+    use crate::lets_be_rational::black;
+
+    #[test]
+    fn fast_path_agrees_with_reference() {
+        // The boundaries between the expansions: the small-t expansion applies below s = 4 ε^(1/16), and the
+        // asymptotic expansion below x = -10 s.
+        const SMALL_T_BOUNDARY: f64 = 4.0 * SIXTEENTH_ROOT_DBL_EPSILON;
+        let f = 100.0;
+        for x in [0.0_f64, -1e-8, -1e-3, -0.1, -1.0, -4.0, -10.0, -40.0] {
+            let mut ss = vec![1e-3, 0.05, 0.2, 1.0, 3.0, 10.0, 30.0];
+            for boundary in [SMALL_T_BOUNDARY, -x / 10.0] {
+                ss.extend([boundary * (1.0 - 1e-9), boundary, boundary * (1.0 + 1e-9)]);
+            }
+            for s in ss.into_iter().filter(|&s| s > 0.0) {
+                for (x, k) in [(x, f * (-x).exp()), (-x, f * x.exp())] {
+                    for q in [true, false] {
+                        let (forward_leg, strike_leg) = legs(f, k, s, 1.0, q);
+                        let d1 = x / s + 0.5 * s;
+                        let tolerance = 16.0
+                            * f64::EPSILON
+                            * (1.0 + d1 * d1)
+                            * forward_leg.abs().max(strike_leg.abs());
+                        let fast = black(f, k, s, 1.0, q);
+                        let reference = black_reference(f, k, s, 1.0, q);
+                        assert!(
+                            (fast - reference).abs() <= tolerance,
+                            "x={x}, s={s}, q={q}: {fast} vs {reference}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
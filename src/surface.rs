@@ -0,0 +1,277 @@
+use crate::lets_be_rational::implied_black_volatility;
+use crate::rational_cubic::{
+    minimum_rational_cubic_control_parameter, rational_cubic_interpolation,
+};
+
+/// A grid of implied black volatilities over strikes and expiries, inverted from option prices.
+///
+/// Each cell holds `None` if its price has no finite implied volatility, so that bad quotes stay visible.
+///
+/// # Examples
+///
+/// ```
+/// use implied_vol::VolSurface;
+///
+/// let forwards = [100.0, 101.0];
+/// let expiries = [0.5, 1.0];
+/// let strikes = [90.0, 110.0];
+/// let price = implied_vol::calculate_european_option_price_by_black_scholes;
+/// let near = [price(100.0, 90.0, 0.25, 0.5, true), price(100.0, 110.0, 0.2, 0.5, true)];
+/// let far = [price(101.0, 90.0, 0.24, 1.0, true), 200.0];
+/// let surface = VolSurface::from_prices(&forwards, &expiries, &strikes, &[&near, &far], true);
+/// assert!((surface.cell(0, 1).unwrap() - 0.2).abs() <= 1e-14);
+/// assert_eq!(surface.cell(1, 1), None);
+/// assert!((surface.vol(100.0, 0.5).unwrap() - 0.225).abs() <= 1e-14);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolSurface {
+    expiries: Vec<f64>,
+    strikes: Vec<f64>,
+    vols: Vec<Option<f64>>,
+}
+
+impl VolSurface {
+    /// Inverts every price of a grid to an implied black volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward_curve` - The forward price of the underlying asset at each expiry.
+    /// * `expiries` - The times to expiration in years, strictly increasing.
+    /// * `strikes` - The strike prices, strictly increasing.
+    /// * `prices` - The option prices, one row per expiry with one price per strike.
+    /// * `is_call` - A boolean flag indicating whether the options are calls (true) or puts (false).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `expiries` or `strikes` is not strictly increasing, if `forward_curve` or `prices`
+    /// does not have one entry per expiry, or if a row of `prices` does not have one entry per strike.
+    pub fn from_prices(
+        forward_curve: &[f64],
+        expiries: &[f64],
+        strikes: &[f64],
+        prices: &[&[f64]],
+        is_call: bool,
+    ) -> Self {
+        assert!(expiries.windows(2).all(|w| w[0] < w[1]));
+        assert!(strikes.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(forward_curve.len(), expiries.len());
+        assert_eq!(prices.len(), expiries.len());
+        let mut vols = Vec::with_capacity(expiries.len() * strikes.len());
+        for ((&f, &t), row) in forward_curve.iter().zip(expiries).zip(prices) {
+            assert_eq!(row.len(), strikes.len());
+            vols.extend(row.iter().zip(strikes).map(|(&price, &k)| {
+                let sigma = implied_black_volatility(price, f, k, t, is_call);
+                sigma.is_finite().then_some(sigma)
+            }));
+        }
+        Self {
+            expiries: expiries.to_vec(),
+            strikes: strikes.to_vec(),
+            vols,
+        }
+    }
+
+    /// Returns the expiry axis.
+    pub fn expiries(&self) -> &[f64] {
+        &self.expiries
+    }
+
+    /// Returns the strike axis.
+    pub fn strikes(&self) -> &[f64] {
+        &self.strikes
+    }
+
+    /// Returns the implied volatility at the `i`-th expiry and the `j`-th strike.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `i` or `j` is out of bounds.
+    pub fn cell(&self, i: usize, j: usize) -> Option<f64> {
+        assert!(j < self.strikes.len());
+        self.vols[i * self.strikes.len() + j]
+    }
+
+    /// Interpolates the implied volatility at a strike and an expiry within the grid.
+    ///
+    /// The volatility is interpolated in the strike by a shape-preserving rational cubic, which is monotone wherever
+    /// the volatilities at the nodes are, and the total variance `σ²T` linearly in the expiry, which keeps the total
+    /// variance monotone in the expiry if it is so at the nodes.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated volatility, or `None` if the point lies outside the grid or a surrounding cell is `None`.
+    pub fn vol(&self, strike: f64, expiry: f64) -> Option<f64> {
+        let (i, u) = locate(&self.expiries, expiry)?;
+        let (j, v) = locate(&self.strikes, strike)?;
+        let along_strikes = |i: usize| -> Option<f64> {
+            let left = self.cell(i, j)?;
+            if v == 0.0 {
+                return Some(left);
+            }
+            let right = self.cell(i, j + 1)?;
+            let (k_l, k_r) = (self.strikes[j], self.strikes[j + 1]);
+            let secant = (right - left) / (k_r - k_l);
+            let previous = j.checked_sub(1).and_then(|j| self.secant(i, j));
+            let d_l = node_slope(previous.unwrap_or(secant), secant);
+            let d_r = node_slope(secant, self.secant(i, j + 1).unwrap_or(secant));
+            let r = minimum_rational_cubic_control_parameter(d_l, d_r, secant, true);
+            Some(rational_cubic_interpolation(
+                strike, k_l, k_r, left, right, d_l, d_r, r,
+            ))
+        };
+        let near = along_strikes(i)?;
+        if u == 0.0 {
+            return Some(near);
+        }
+        let far = along_strikes(i + 1)?;
+        let (t0, t1) = (self.expiries[i], self.expiries[i + 1]);
+        let variance = near * near * t0 + u * (far * far * t1 - near * near * t0);
+        Some((variance / expiry).sqrt())
+    }
+
+    /// Returns the slope of the volatility between the `j`-th and the next strike at the `i`-th expiry, if both
+    /// cells are present.
+    fn secant(&self, i: usize, j: usize) -> Option<f64> {
+        if j + 1 >= self.strikes.len() {
+            return None;
+        }
+        let (left, right) = (self.cell(i, j)?, self.cell(i, j + 1)?);
+        Some((right - left) / (self.strikes[j + 1] - self.strikes[j]))
+    }
+}
+
+/// The slope at a node as the harmonic mean of the secants on either side, or zero at a local extremum, so that the
+/// slopes of a monotone stretch keep its sign.
+fn node_slope(left: f64, right: f64) -> f64 {
+    if left * right <= 0.0 {
+        return 0.0;
+    }
+    2.0 * left * right / (left + right)
+}
+
+/// Finds the interval `[axis[i], axis[i + 1])` containing `x` and the relative position of `x` in it.
+fn locate(axis: &[f64], x: f64) -> Option<(usize, f64)> {
+    let last = axis.len().checked_sub(1)?;
+    if x == axis[last] {
+        return Some((last, 0.0));
+    }
+    let i = axis.partition_point(|&a| a <= x).checked_sub(1)?;
+    if i == last {
+        return None;
+    }
+    Some((i, (x - axis[i]) / (axis[i + 1] - axis[i])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lets_be_rational::black;
+
+    #[test]
+    fn synthetic_surface_reconstructs_vols() {
+        let expiries = [0.25, 0.5, 1.0, 2.0];
+        let strikes = [80.0, 90.0, 100.0, 110.0, 120.0];
+        let forwards = [100.0, 100.5, 101.0, 102.0];
+        let sigma = |k: f64, t: f64| 0.2 + 0.1 * ((k - 100.0) / 100.0).powi(2) + 0.01 * t;
+        for is_call in [true, false] {
+            let rows: Vec<Vec<f64>> = expiries
+                .iter()
+                .zip(&forwards)
+                .map(|(&t, &f)| {
+                    strikes
+                        .iter()
+                        .map(|&k| black(f, k, sigma(k, t), t, is_call))
+                        .collect()
+                })
+                .collect();
+            let prices: Vec<&[f64]> = rows.iter().map(Vec::as_slice).collect();
+            let surface = VolSurface::from_prices(&forwards, &expiries, &strikes, &prices, is_call);
+            for (i, &t) in expiries.iter().enumerate() {
+                for (j, &k) in strikes.iter().enumerate() {
+                    let expected = sigma(k, t);
+                    assert!((surface.cell(i, j).unwrap() - expected).abs() <= 1e-13);
+                    assert!((surface.vol(k, t).unwrap() - expected).abs() <= 1e-13);
+                }
+            }
+            let between = surface.vol(95.0, 0.75).unwrap();
+            assert!(between > sigma(100.0, 0.5) && between < sigma(90.0, 1.0));
+            assert_eq!(surface.vol(79.0, 1.0), None);
+            assert_eq!(surface.vol(100.0, 2.5), None);
+        }
+    }
+
+    #[test]
+    fn interpolation_is_monotone_in_the_strike() {
+        // A skew flattening out to the right, where a cubic through the nodes would overshoot.
+        let strikes = [60.0, 80.0, 90.0, 100.0, 140.0, 200.0];
+        let sigmas = [0.45, 0.3, 0.22, 0.2, 0.199, 0.199];
+        let row: Vec<f64> = strikes
+            .iter()
+            .zip(sigmas)
+            .map(|(&k, sigma)| black(100.0, k, sigma, 1.0, true))
+            .collect();
+        let surface = VolSurface::from_prices(&[100.0], &[1.0], &strikes, &[&row], true);
+        let mut previous = f64::INFINITY;
+        for n in 0..=1400 {
+            let k = 60.0 + 0.1 * n as f64;
+            let sigma = surface.vol(k, 1.0).unwrap();
+            assert!(sigma <= previous + 1e-14, "{k} {sigma} {previous}");
+            assert!((0.199 - 1e-14..=0.45 + 1e-14).contains(&sigma));
+            previous = sigma;
+        }
+        let flat = surface.vol(170.0, 1.0).unwrap();
+        assert!((flat - 0.199).abs() <= 1e-13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsorted_strikes_are_rejected() {
+        VolSurface::from_prices(&[100.0], &[1.0], &[110.0, 90.0], &[&[1.0, 12.0]], true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_strikes_are_rejected() {
+        VolSurface::from_prices(&[100.0], &[1.0], &[90.0, 90.0], &[&[12.0, 12.0]], true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsorted_expiries_are_rejected() {
+        VolSurface::from_prices(
+            &[100.0, 100.0],
+            &[1.0, 0.5],
+            &[90.0],
+            &[&[12.0], &[11.0]],
+            true,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_expiries_are_rejected() {
+        VolSurface::from_prices(
+            &[100.0, 100.0],
+            &[1.0, 1.0],
+            &[90.0],
+            &[&[12.0], &[12.0]],
+            true,
+        );
+    }
+
+    #[test]
+    fn bad_quotes_are_visible() {
+        let surface = VolSurface::from_prices(
+            &[100.0],
+            &[1.0],
+            &[90.0, 100.0, 110.0],
+            &[&[5.0, 8.0, 150.0]],
+            true,
+        );
+        assert_eq!(surface.cell(0, 0), None);
+        assert!(surface.cell(0, 1).is_some());
+        assert_eq!(surface.cell(0, 2), None);
+        assert!(surface.vol(100.0, 1.0).is_some());
+        assert_eq!(surface.vol(95.0, 1.0), None);
+    }
+}